@@ -39,6 +39,18 @@ pub trait Copyable {
     }
 }
 
+/// Implemented for access types that can be restricted to the access type `B`.
+///
+/// An access type can always be restricted to itself and to [`NoAccess`]. In addition,
+/// [`ReadWrite`] can be restricted to [`ReadOnly`] and [`WriteOnly`].
+pub trait RestrictTo<B: Access>: Access {
+    /// Ensures that this trait cannot be implemented outside of this crate.
+    #[doc(hidden)]
+    fn _private() -> _Private {
+        _Private
+    }
+}
+
 impl<T> Access for T
 where
     T: Readable + Default + Copy,
@@ -48,6 +60,7 @@ where
 
 /// Zero-sized marker type for allowing both read and write access.
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ReadWrite;
 impl Readable for ReadWrite {
     type RestrictShared = ReadOnly;
@@ -56,6 +69,7 @@ impl Writable for ReadWrite {}
 
 /// Zero-sized marker type for allowing only read access.
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ReadOnly;
 impl Readable for ReadOnly {
     type RestrictShared = ReadOnly;
@@ -64,6 +78,7 @@ impl Copyable for ReadOnly {}
 
 /// Zero-sized marker type for allowing only write access.
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct WriteOnly;
 impl Access for WriteOnly {
     type RestrictShared = NoAccess;
@@ -72,12 +87,23 @@ impl Writable for WriteOnly {}
 
 /// Zero-sized marker type that grants no access.
 #[derive(Debug, Default, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct NoAccess;
 impl Access for NoAccess {
     type RestrictShared = NoAccess;
 }
 impl Copyable for NoAccess {}
 
+impl RestrictTo<ReadWrite> for ReadWrite {}
+impl RestrictTo<ReadOnly> for ReadWrite {}
+impl RestrictTo<WriteOnly> for ReadWrite {}
+impl RestrictTo<NoAccess> for ReadWrite {}
+impl RestrictTo<ReadOnly> for ReadOnly {}
+impl RestrictTo<NoAccess> for ReadOnly {}
+impl RestrictTo<WriteOnly> for WriteOnly {}
+impl RestrictTo<NoAccess> for WriteOnly {}
+impl RestrictTo<NoAccess> for NoAccess {}
+
 #[non_exhaustive]
 #[doc(hidden)]
 pub struct _Private;
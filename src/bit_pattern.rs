@@ -0,0 +1,145 @@
+//! Traits for types that can be safely read from memory with arbitrary contents.
+//!
+//! A volatile read of a type with invalid bit patterns, e.g. `bool` or `char`, is undefined
+//! behavior if the memory holds such an invalid pattern. Since device memory can hold
+//! arbitrary bits, values of such types should not be read directly through
+//! [`VolatilePtr::read`][crate::VolatilePtr::read].
+//!
+//! Types implementing [`AnyBitPattern`] are valid for every bit pattern and can be read through
+//! [`VolatilePtr::read_raw`][crate::VolatilePtr::read_raw]. Types implementing
+//! [`CheckedBitPattern`] can be read through
+//! [`VolatilePtr::read_checked`][crate::VolatilePtr::read_checked], which validates the bits
+//! before converting them.
+
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+/// Types that are valid for any bit pattern.
+///
+/// This trait is implemented for all primitive integer and floating point types, for
+/// `Option<NonZero*>` types, and for arrays of such types.
+///
+/// ## Safety
+///
+/// Every bit pattern of `size_of::<Self>()` bytes must be a valid value of the type. In
+/// particular, the type must not contain any padding bytes.
+///
+/// ## Example
+///
+/// Structs can opt in if all of their fields implement `AnyBitPattern` and they have no
+/// padding:
+///
+/// ```
+/// use volatile::bit_pattern::AnyBitPattern;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Regs { control: u32, status: u32 }
+///
+/// // SAFETY: `Regs` consists of integers and has no padding
+/// unsafe impl AnyBitPattern for Regs {}
+/// ```
+pub unsafe trait AnyBitPattern: Copy {}
+
+macro_rules! impl_any_bit_pattern {
+    ($($ty:ty),*) => {
+        $(unsafe impl AnyBitPattern for $ty {})*
+    };
+}
+
+impl_any_bit_pattern!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl_any_bit_pattern!(
+    Option<NonZeroU8>,
+    Option<NonZeroU16>,
+    Option<NonZeroU32>,
+    Option<NonZeroU64>,
+    Option<NonZeroU128>,
+    Option<NonZeroUsize>,
+    Option<NonZeroI8>,
+    Option<NonZeroI16>,
+    Option<NonZeroI32>,
+    Option<NonZeroI64>,
+    Option<NonZeroI128>,
+    Option<NonZeroIsize>
+);
+
+unsafe impl<T, const N: usize> AnyBitPattern for [T; N] where T: AnyBitPattern {}
+
+/// Types whose valid bit patterns can be checked at runtime.
+///
+/// The value is first read as [`Bits`][Self::Bits], which is valid for any bit pattern, and
+/// only converted if [`is_valid_bit_pattern`][Self::is_valid_bit_pattern] returns `true`.
+///
+/// This trait is implemented for all [`AnyBitPattern`] types, for `bool`, `char`, and the
+/// `NonZero*` types.
+///
+/// ## Safety
+///
+/// - `Bits` must have the same size as `Self` and an alignment that is not larger than the
+///   alignment of `Self`.
+/// - If `is_valid_bit_pattern` returns `true`, the given bits must be a valid value of `Self`.
+pub unsafe trait CheckedBitPattern: Copy {
+    /// A type with the same size as `Self` that is valid for any bit pattern.
+    type Bits: AnyBitPattern;
+
+    /// Returns whether the given bits are a valid value of `Self`.
+    fn is_valid_bit_pattern(bits: &Self::Bits) -> bool;
+}
+
+unsafe impl<T> CheckedBitPattern for T
+where
+    T: AnyBitPattern,
+{
+    type Bits = T;
+
+    fn is_valid_bit_pattern(_bits: &T) -> bool {
+        true
+    }
+}
+
+unsafe impl CheckedBitPattern for bool {
+    type Bits = u8;
+
+    fn is_valid_bit_pattern(bits: &u8) -> bool {
+        *bits <= 1
+    }
+}
+
+unsafe impl CheckedBitPattern for char {
+    type Bits = u32;
+
+    fn is_valid_bit_pattern(bits: &u32) -> bool {
+        char::from_u32(*bits).is_some()
+    }
+}
+
+macro_rules! impl_checked_non_zero {
+    ($($ty:ty => $bits:ty),*) => {
+        $(
+            unsafe impl CheckedBitPattern for $ty {
+                type Bits = $bits;
+
+                fn is_valid_bit_pattern(bits: &$bits) -> bool {
+                    *bits != 0
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_non_zero!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
@@ -0,0 +1,129 @@
+//! Conversions between raw register values and typed values.
+//!
+//! The [`VolatilePtr::read_as`][crate::VolatilePtr::read_as] and
+//! [`VolatilePtr::write_from`][crate::VolatilePtr::write_from] methods read and write values
+//! through [`TryFrom`] and [`Into`] conversions. Raw values that don't correspond to a valid
+//! typed value are reported as [`InvalidValue`] errors.
+//!
+//! The [`volatile_enum!`][crate::volatile_enum!] macro declares field-less enums with the
+//! required conversions.
+
+use core::fmt;
+
+/// A raw value that could not be converted to the requested type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidValue<T>(pub T);
+
+impl<T> InvalidValue<T> {
+    /// Returns the raw value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> fmt::Display for InvalidValue<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value {:?}", self.0)
+    }
+}
+
+/// Declares a field-less enum with conversions from and to its integer representation.
+///
+/// The macro adds a `#[repr]` attribute for the given integer type and implements
+/// `TryFrom<int>` with [`InvalidValue`][crate::convert::InvalidValue] as error type, and
+/// `From<Enum> for int`. This makes the enum usable with
+/// [`VolatilePtr::read_as`][crate::VolatilePtr::read_as] and
+/// [`VolatilePtr::write_from`][crate::VolatilePtr::write_from]. Values without a
+/// corresponding variant, e.g. reserved values of a mode register, are reported as errors.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{convert::InvalidValue, volatile_enum, VolatilePtr};
+/// use core::ptr::NonNull;
+///
+/// volatile_enum! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub enum Mode: u32 {
+///         Off = 0,
+///         Run = 1,
+///         Sleep = 3,
+///     }
+/// }
+///
+/// let mut register = 1u32;
+/// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut register)) };
+/// assert_eq!(volatile.read_as::<Mode>(), Ok(Mode::Run));
+///
+/// volatile.write_from(Mode::Sleep);
+/// assert_eq!(volatile.read(), 3);
+///
+/// volatile.write(2);
+/// assert_eq!(volatile.read_as::<Mode>(), Err(InvalidValue(2)));
+/// ```
+#[macro_export]
+macro_rules! volatile_enum {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident : $repr:ident {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident $(= $value:expr)?
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr($repr)]
+        $vis enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant $(= $value)?,
+            )+
+        }
+
+        impl core::convert::TryFrom<$repr> for $name {
+            type Error = $crate::convert::InvalidValue<$repr>;
+
+            fn try_from(value: $repr) -> Result<Self, $crate::convert::InvalidValue<$repr>> {
+                $(
+                    if value == $name::$variant as $repr {
+                        return Ok($name::$variant);
+                    }
+                )+
+                Err($crate::convert::InvalidValue(value))
+            }
+        }
+
+        impl core::convert::From<$name> for $repr {
+            fn from(value: $name) -> $repr {
+                value as $repr
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InvalidValue;
+
+    volatile_enum! {
+        #[derive(Debug, PartialEq)]
+        enum State: i8 {
+            Error = -1,
+            Idle = 0,
+            /// doc comments on variants are supported
+            Busy,
+        }
+    }
+
+    #[test]
+    fn test_enum_conversions() {
+        assert_eq!(State::try_from(-1), Ok(State::Error));
+        assert_eq!(State::try_from(1), Ok(State::Busy));
+        assert_eq!(State::try_from(2), Err(InvalidValue(2)));
+        assert_eq!(i8::from(State::Idle), 0);
+    }
+}
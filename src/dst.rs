@@ -0,0 +1,114 @@
+//! Support for dynamically sized structs with a trailing slice.
+//!
+//! Many device and firmware tables consist of a fixed header followed by a variable number of
+//! entries. Such tables can be described by structs whose last field is a slice:
+//!
+//! ```
+//! #[repr(C)]
+//! struct Table {
+//!     len: u32,
+//!     flags: u32,
+//!     entries: [u64],
+//! }
+//! ```
+//!
+//! After implementing [`SliceDst`] for such a struct through the
+//! [`slice_dst!`][crate::slice_dst!] macro, a [`VolatilePtr`][crate::VolatilePtr] to it can be
+//! created through [`VolatilePtr::from_raw_parts`][crate::VolatilePtr::from_raw_parts]. The
+//! [`map_field!`][crate::map_field!] macro works for both the header fields and the trailing
+//! slice. The length of the trailing slice is taken from the pointer metadata.
+
+use core::ptr::NonNull;
+
+/// Dynamically sized types whose pointer metadata is the length of a trailing slice.
+///
+/// Implement this trait for custom structs through the [`slice_dst!`][crate::slice_dst!] macro.
+///
+/// ## Safety
+///
+/// [`ptr_from_raw_parts`][Self::ptr_from_raw_parts] must return a pointer to the given
+/// address whose trailing slice has exactly `len` elements.
+pub unsafe trait SliceDst {
+    /// Creates a pointer to the given address with a trailing slice of `len` elements.
+    fn ptr_from_raw_parts(data: NonNull<u8>, len: usize) -> NonNull<Self>;
+}
+
+unsafe impl<T> SliceDst for [T] {
+    fn ptr_from_raw_parts(data: NonNull<u8>, len: usize) -> NonNull<Self> {
+        NonNull::slice_from_raw_parts(data.cast(), len)
+    }
+}
+
+/// Implements [`SliceDst`][crate::dst::SliceDst] for structs whose last field is a slice.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{map_field, slice_dst, VolatilePtr};
+/// use core::ptr::NonNull;
+///
+/// #[repr(C)]
+/// struct Table {
+///     len: u32,
+///     entries: [u32],
+/// }
+/// slice_dst!(Table);
+///
+/// let mut memory = [3u32, 10, 20, 30];
+/// let addr = NonNull::from(&mut memory).cast::<u8>();
+/// let table = unsafe { VolatilePtr::<Table>::from_raw_parts(addr, 3) };
+///
+/// let len = map_field!(table.len).read();
+/// let entries = map_field!(table.entries);
+/// assert_eq!(entries.as_raw_ptr().len(), len as usize);
+/// ```
+///
+/// The macro only accepts structs whose last field is a slice. Trait objects are rejected:
+///
+/// ```compile_fail
+/// use volatile::slice_dst;
+///
+/// struct Dynamic {
+///     value: dyn core::fmt::Debug,
+/// }
+/// slice_dst!(Dynamic);
+/// ```
+///
+/// Sized structs are rejected too:
+///
+/// ```compile_fail
+/// use volatile::slice_dst;
+///
+/// #[repr(C)]
+/// struct Header {
+///     len: u32,
+///     entries: [u32; 4],
+/// }
+/// slice_dst!(Header);
+/// ```
+#[macro_export]
+macro_rules! slice_dst {
+    ($($ty:ident),+ $(,)?) => {
+        $(
+            // Pointers to sized types are thin, so this rejects structs without a trailing
+            // slice. The `as` cast below would silently drop the length for them.
+            const _: () = assert!(
+                core::mem::size_of::<*const $ty>() == 2 * core::mem::size_of::<usize>(),
+                "`slice_dst!` requires a struct whose last field is a slice",
+            );
+
+            unsafe impl $crate::dst::SliceDst for $ty {
+                fn ptr_from_raw_parts(
+                    data: core::ptr::NonNull<u8>,
+                    len: usize,
+                ) -> core::ptr::NonNull<Self> {
+                    // The `as` cast keeps the slice length as pointer metadata. It does not
+                    // compile for trait objects, and sized types are rejected by the assertion
+                    // above.
+                    let slice: *mut [u8] = core::ptr::slice_from_raw_parts_mut(data.as_ptr(), len);
+                    unsafe { core::ptr::NonNull::new_unchecked(slice as *mut Self) }
+                }
+            }
+        )+
+    };
+}
@@ -42,9 +42,41 @@
 #![warn(missing_docs)]
 #![deny(unsafe_op_in_unsafe_fn)]
 
-pub use volatile_ptr::VolatilePtr;
+pub use volatile_access::VolatileAccess;
+pub use volatile_ptr::{DebugValue, SendVolatilePtr, VolatilePtr};
 pub use volatile_ref::VolatileRef;
 
 pub mod access;
+pub mod bit_pattern;
+pub mod convert;
+pub mod dst;
+pub mod mailbox;
+#[cfg(target_has_atomic = "32")]
+pub mod ring;
+pub mod shared;
+pub mod static_mmio;
+#[cfg(all(feature = "unix", unix))]
+pub mod unix;
+mod volatile_access;
 mod volatile_ptr;
 mod volatile_ref;
+pub mod width;
+pub mod writer;
+
+#[doc(hidden)]
+pub mod __private {
+    use core::{mem::MaybeUninit, ptr::NonNull};
+
+    /// Casts a pointer to a `MaybeUninit<T>` to a pointer to `T` without creating a reference.
+    pub const fn uninit_inner<T>(pointer: NonNull<MaybeUninit<T>>) -> *mut T {
+        pointer.as_ptr().cast()
+    }
+
+    /// Fails to compile if the referenced type doesn't implement `AnyBitPattern`.
+    pub fn assert_any_bit_pattern<T: crate::bit_pattern::AnyBitPattern>(_value: &T) {}
+
+    /// Casts a field pointer back to a `MaybeUninit` pointer.
+    pub fn uninit_field<T>(field: *mut T) -> NonNull<MaybeUninit<T>> {
+        NonNull::new(field.cast()).unwrap()
+    }
+}
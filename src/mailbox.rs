@@ -0,0 +1,300 @@
+//! Mailbox abstraction for request/response communication with firmware or devices.
+//!
+//! Sending a command through a mailbox always follows the same sequence:
+//!
+//! 1. Write the request to the payload registers.
+//! 2. Ring the doorbell register.
+//! 3. Wait until the status register signals completion, either by polling or by waiting
+//!    for an interrupt.
+//! 4. Read the response from the payload registers.
+//!
+//! The [`Mailbox`] type enforces this order through its API: [`Mailbox::send`] returns a
+//! [`Pending`] request, which turns into a [`Response`] only after the status register reported
+//! completion. Memory fences are issued between the individual steps.
+//!
+//! Note that the fences only order memory accesses from the perspective of other CPU cores. Some
+//! architectures require additional barrier instructions to order accesses to device memory.
+
+use core::{
+    hint,
+    sync::atomic::{fence, Ordering},
+};
+
+use crate::{
+    access::{ReadOnly, ReadWrite, WriteOnly},
+    VolatilePtr,
+};
+
+/// A mailbox consisting of payload, doorbell, and status registers.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{mailbox::Mailbox, VolatilePtr};
+/// use core::ptr::NonNull;
+///
+/// let mut payload = [0u32; 4];
+/// let mut doorbell = 0u32;
+/// let mut status = 0u32;
+/// # let status_ptr = NonNull::from(&mut status);
+///
+/// let mut mailbox = unsafe {
+///     Mailbox::new(
+///         VolatilePtr::new(NonNull::from(&mut payload[..])),
+///         VolatilePtr::new(NonNull::from(&mut doorbell)).write_only(),
+///         VolatilePtr::new(status_ptr).read_only(),
+///     )
+/// };
+///
+/// let pending = mailbox.send(&[1, 2], 1);
+/// // the firmware processes the request and sets the completion flag
+/// # let fw = unsafe { VolatilePtr::new(status_ptr) };
+/// # fw.write(1);
+/// let response = pending.wait(1000, |status| status & 1 != 0).unwrap();
+/// assert_eq!(response.status(), 1);
+///
+/// let mut buf = [0; 2];
+/// response.read_into(&mut buf);
+/// assert_eq!(buf, [1, 2]);
+/// ```
+#[derive(Debug)]
+pub struct Mailbox<'a, T, D, S> {
+    payload: VolatilePtr<'a, [T], ReadWrite>,
+    doorbell: VolatilePtr<'a, D, WriteOnly>,
+    status: VolatilePtr<'a, S, ReadOnly>,
+}
+
+impl<'a, T, D, S> Mailbox<'a, T, D, S>
+where
+    T: Copy,
+    D: Copy,
+    S: Copy,
+{
+    /// Creates a mailbox from the given payload, doorbell, and status registers.
+    pub fn new(
+        payload: VolatilePtr<'a, [T], ReadWrite>,
+        doorbell: VolatilePtr<'a, D, WriteOnly>,
+        status: VolatilePtr<'a, S, ReadOnly>,
+    ) -> Self {
+        Mailbox {
+            payload,
+            doorbell,
+            status,
+        }
+    }
+
+    /// Writes the given `request` to the payload registers and then writes `ring` to the
+    /// doorbell register.
+    ///
+    /// The request is written to the start of the payload registers. Use the returned
+    /// [`Pending`] value to wait for the completion of the request.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `request` is longer than the payload registers.
+    pub fn send(&mut self, request: &[T], ring: D) -> Pending<'_, 'a, T, D, S> {
+        let len = self.payload.as_raw_ptr().len();
+        assert!(
+            request.len() <= len,
+            "request is larger than the mailbox payload"
+        );
+        for (i, value) in request.iter().enumerate() {
+            // SAFETY: we checked that the request fits into the payload
+            unsafe { self.payload.element_unchecked(i) }.write(*value);
+        }
+        // make sure that the payload is written before the doorbell rings
+        fence(Ordering::Release);
+        self.doorbell.write(ring);
+        Pending { mailbox: self }
+    }
+}
+
+/// A request that was sent through a [`Mailbox`], but not completed yet.
+///
+/// The request completes when the status register holds a value for which the `is_complete`
+/// function passed to [`poll`][Self::poll] or the `wait` methods returns `true`.
+///
+/// For interrupt-driven completion, call [`poll`][Self::poll] from the wakeup path, or use
+/// [`wait_with`][Self::wait_with] with a function that waits for the next interrupt.
+#[derive(Debug)]
+pub struct Pending<'m, 'a, T, D, S> {
+    mailbox: &'m mut Mailbox<'a, T, D, S>,
+}
+
+impl<'m, 'a, T, D, S> Pending<'m, 'a, T, D, S>
+where
+    T: Copy,
+    D: Copy,
+    S: Copy,
+{
+    /// Reads the status register once and checks whether the request is complete.
+    ///
+    /// Returns the [`Response`] on completion, or gives back the pending request otherwise.
+    pub fn poll<F>(self, is_complete: F) -> Result<Response<'m, 'a, T, D, S>, Self>
+    where
+        F: FnOnce(S) -> bool,
+    {
+        let status = self.mailbox.status.read();
+        if is_complete(status) {
+            // make sure that the response is not read before the completion was observed
+            fence(Ordering::Acquire);
+            Ok(Response {
+                mailbox: self.mailbox,
+                status,
+            })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Busy-waits until the request is complete, reading the status register at most
+    /// `max_polls` times.
+    ///
+    /// Gives back the pending request if it did not complete in time. If `max_polls` is zero,
+    /// the status register is not read at all.
+    pub fn wait<F>(
+        self,
+        max_polls: usize,
+        mut is_complete: F,
+    ) -> Result<Response<'m, 'a, T, D, S>, Self>
+    where
+        F: FnMut(S) -> bool,
+    {
+        if max_polls == 0 {
+            return Err(self);
+        }
+        let mut polls = 0;
+        self.wait_with(
+            || {
+                polls += 1;
+                hint::spin_loop();
+                polls < max_polls
+            },
+            &mut is_complete,
+        )
+    }
+
+    /// Waits until the request is complete, calling `idle` between two reads of the status
+    /// register.
+    ///
+    /// The `idle` function can be used to wait for an interrupt or to implement a custom
+    /// timeout. If it returns `false`, waiting is aborted and the pending request is given back.
+    pub fn wait_with<I, F>(
+        mut self,
+        mut idle: I,
+        mut is_complete: F,
+    ) -> Result<Response<'m, 'a, T, D, S>, Self>
+    where
+        I: FnMut() -> bool,
+        F: FnMut(S) -> bool,
+    {
+        loop {
+            self = match self.poll(&mut is_complete) {
+                Ok(response) => return Ok(response),
+                Err(pending) => pending,
+            };
+            if !idle() {
+                return Err(self);
+            }
+        }
+    }
+}
+
+/// The response to a completed [`Mailbox`] request.
+#[derive(Debug)]
+pub struct Response<'m, 'a, T, D, S> {
+    mailbox: &'m mut Mailbox<'a, T, D, S>,
+    status: S,
+}
+
+impl<T, D, S> Response<'_, '_, T, D, S>
+where
+    T: Copy,
+    S: Copy,
+{
+    /// Returns the status value that signaled the completion.
+    pub fn status(&self) -> S {
+        self.status
+    }
+
+    /// Returns a read-only pointer to the payload registers.
+    pub fn payload(&self) -> VolatilePtr<'_, [T], ReadOnly> {
+        self.mailbox.payload.read_only()
+    }
+
+    /// Reads the start of the payload registers into `dst`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `dst` is longer than the payload registers.
+    pub fn read_into(&self, dst: &mut [T]) {
+        let payload = self.payload();
+        assert!(
+            dst.len() <= payload.as_raw_ptr().len(),
+            "destination is larger than the mailbox payload"
+        );
+        for (i, value) in dst.iter_mut().enumerate() {
+            // SAFETY: we checked that `dst` is not larger than the payload
+            *value = unsafe { payload.element_unchecked(i) }.read();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Mailbox;
+    use crate::VolatilePtr;
+    use core::ptr::NonNull;
+
+    #[test]
+    fn test_timeout() {
+        let mut payload = [0u8; 2];
+        let mut doorbell = 0u8;
+        let mut status = 0u8;
+        {
+            let status_ptr = NonNull::from(&mut status);
+            let mut mailbox = unsafe {
+                Mailbox::new(
+                    VolatilePtr::new(NonNull::from(&mut payload[..])),
+                    VolatilePtr::new(NonNull::from(&mut doorbell)).write_only(),
+                    VolatilePtr::new(status_ptr).read_only(),
+                )
+            };
+            let status_ptr = unsafe { VolatilePtr::new(status_ptr) };
+
+            let mut polls = 0;
+            let pending = mailbox
+                .send(&[7], 0xd0)
+                .wait(0, |_| {
+                    polls += 1;
+                    true
+                })
+                .unwrap_err();
+            assert_eq!(polls, 0);
+            let pending = pending
+                .wait(3, |s| {
+                    polls += 1;
+                    s != 0
+                })
+                .unwrap_err();
+            assert_eq!(polls, 3);
+
+            // simulate an interrupt that completes the request
+            let response = pending
+                .wait_with(
+                    || {
+                        status_ptr.write(2);
+                        true
+                    },
+                    |s| s != 0,
+                )
+                .unwrap();
+            assert_eq!(response.status(), 2);
+            let mut buf = [0];
+            response.read_into(&mut buf);
+            assert_eq!(buf, [7]);
+        }
+        // the mailbox is gone, so the doorbell can be accessed directly again
+        assert_eq!(doorbell, 0xd0);
+    }
+}
@@ -0,0 +1,483 @@
+//! Single-producer/single-consumer ring buffer in volatile shared memory.
+//!
+//! The ring buffer consists of two `u32` indices and a data area. The `tail` index is only
+//! written by the producer and points to the next slot that will be written. The `head` index
+//! is only written by the consumer and points to the next slot that will be read. Both sides
+//! can live on different cores, in different processes, or on a co-processor that implements
+//! the same protocol.
+//!
+//! The data area is accessed through volatile reads and writes. The indices are accessed as
+//! [`AtomicU32`] values with acquire/release ordering, which orders the data accesses before
+//! the index updates. This also makes it possible to use the two halves from different threads.
+//!
+//! This module is only available on targets that support 32-bit atomic operations.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::{
+    access::{ReadOnly, ReadWrite, WriteOnly},
+    VolatilePtr,
+};
+
+/// Specifies how the `head` and `tail` indices are mapped to slots of the data area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexMode {
+    /// The indices are kept in the range `0..capacity` and wrap around at the end of the data
+    /// area.
+    ///
+    /// Works for any capacity, but one slot is always kept free to distinguish a full ring
+    /// from an empty one.
+    Wrapping,
+    /// The indices are free-running counters that wrap around at `u32::MAX`. The slot is
+    /// determined by masking the index with `capacity - 1`.
+    ///
+    /// Requires a power-of-two capacity, but all slots can be used.
+    PowerOfTwo,
+}
+
+impl IndexMode {
+    /// Returns the number of occupied slots.
+    fn len(self, head: u32, tail: u32, capacity: u32) -> u32 {
+        match self {
+            IndexMode::Wrapping => {
+                let capacity = u64::from(capacity);
+                let head = u64::from(head) % capacity;
+                let tail = u64::from(tail) % capacity;
+                ((tail + capacity - head) % capacity) as u32
+            }
+            // clamp the length in case the other side wrote an invalid index
+            IndexMode::PowerOfTwo => tail.wrapping_sub(head).min(capacity),
+        }
+    }
+
+    /// Returns the maximum number of occupied slots.
+    fn usable(self, capacity: u32) -> u32 {
+        match self {
+            IndexMode::Wrapping => capacity - 1,
+            IndexMode::PowerOfTwo => capacity,
+        }
+    }
+
+    /// Returns the index that follows `n` slots after `index`.
+    fn advance(self, index: u32, n: u32, capacity: u32) -> u32 {
+        match self {
+            IndexMode::Wrapping => {
+                let capacity = u64::from(capacity);
+                ((u64::from(index) % capacity + u64::from(n)) % capacity) as u32
+            }
+            IndexMode::PowerOfTwo => index.wrapping_add(n),
+        }
+    }
+
+    /// Returns the slot that the given index refers to.
+    ///
+    /// The result is always in bounds, even if the index is invalid.
+    fn slot(self, index: u32, capacity: u32) -> usize {
+        match self {
+            IndexMode::Wrapping => (index % capacity) as usize,
+            IndexMode::PowerOfTwo => (index & (capacity - 1)) as usize,
+        }
+    }
+}
+
+/// A single-producer/single-consumer ring buffer over volatile shared memory.
+///
+/// Use [`split`][Self::split] to get the [`Producer`] and [`Consumer`] halves. If the other
+/// side of the ring lives somewhere else (e.g. on a co-processor), only use the half that
+/// belongs to this side.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{ring::{IndexMode, VolatileSpscRing}, VolatilePtr};
+/// use core::ptr::NonNull;
+///
+/// let (mut head, mut tail) = (0u32, 0u32);
+/// let mut data = [0u8; 4];
+/// let ring = unsafe {
+///     VolatileSpscRing::new(
+///         VolatilePtr::new(NonNull::from(&mut head)),
+///         VolatilePtr::new(NonNull::from(&mut tail)),
+///         VolatilePtr::new(NonNull::from(&mut data[..])),
+///         IndexMode::PowerOfTwo,
+///     )
+/// };
+/// let (producer, consumer) = ring.split();
+///
+/// assert_eq!(producer.push_slice(b"hello"), 4);
+/// assert_eq!(producer.push(b'!'), Err(b'!'));
+///
+/// let mut buf = [0; 3];
+/// assert_eq!(consumer.pop_slice(&mut buf), 3);
+/// assert_eq!(&buf, b"hel");
+/// assert_eq!(consumer.pop(), Some(b'l'));
+/// assert_eq!(consumer.pop(), None);
+/// ```
+pub struct VolatileSpscRing<'a, T> {
+    head: VolatilePtr<'a, u32>,
+    tail: VolatilePtr<'a, u32>,
+    data: VolatilePtr<'a, [T]>,
+    capacity: u32,
+    mode: IndexMode,
+}
+
+impl<'a, T> VolatileSpscRing<'a, T>
+where
+    T: Copy,
+{
+    /// Creates a ring buffer from the given `head` and `tail` indices and data area.
+    ///
+    /// The indices are used as they are, so they need to be initialized already (e.g. to
+    /// zero). The capacity of the ring is the length of `data`.
+    ///
+    /// The [`Producer`] and [`Consumer`] halves can be sent to different threads, even though
+    /// they access the same memory. This is sound because the indices are only accessed
+    /// atomically and a data slot is only accessed by the half that currently owns it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `data` is empty or longer than `u32::MAX`, or if `head` or `tail` are not
+    /// aligned for [`AtomicU32`]. With [`IndexMode::Wrapping`], this function also panics if
+    /// `data` has only one element since one slot is always kept free. With
+    /// [`IndexMode::PowerOfTwo`], it panics if the length of `data` is not a power of two or
+    /// greater than `2^31`.
+    pub fn new(
+        head: VolatilePtr<'a, u32>,
+        tail: VolatilePtr<'a, u32>,
+        data: VolatilePtr<'a, [T]>,
+        mode: IndexMode,
+    ) -> Self {
+        let len = data.as_raw_ptr().len();
+        assert!(len > 0, "ring buffer data area must not be empty");
+        let capacity = u32::try_from(len).expect("ring buffer data area is too large");
+        match mode {
+            IndexMode::Wrapping => assert!(
+                capacity > 1,
+                "ring buffer capacity must be at least 2 with wrapping indices"
+            ),
+            IndexMode::PowerOfTwo => assert!(
+                capacity.is_power_of_two() && capacity <= 1 << 31,
+                "ring buffer capacity must be a power of two"
+            ),
+        }
+        for index in [head, tail] {
+            assert!(
+                index.as_raw_ptr().as_ptr().cast::<AtomicU32>().is_aligned(),
+                "ring buffer indices must be aligned for atomic accesses"
+            );
+        }
+        VolatileSpscRing {
+            head,
+            tail,
+            data,
+            capacity,
+            mode,
+        }
+    }
+
+    /// Returns the maximum number of elements that the ring can hold.
+    pub fn capacity(&self) -> usize {
+        self.mode.usable(self.capacity) as usize
+    }
+
+    /// Splits the ring buffer into its producer and consumer halves.
+    pub fn split(self) -> (Producer<'a, T>, Consumer<'a, T>) {
+        let producer = Producer {
+            head: self.head.read_only(),
+            tail: self.tail,
+            data: self.data.write_only(),
+            capacity: self.capacity,
+            mode: self.mode,
+        };
+        let consumer = Consumer {
+            head: self.head,
+            tail: self.tail.read_only(),
+            data: self.data.read_only(),
+            capacity: self.capacity,
+            mode: self.mode,
+        };
+        (producer, consumer)
+    }
+}
+
+/// The producing half of a [`VolatileSpscRing`].
+pub struct Producer<'a, T> {
+    head: VolatilePtr<'a, u32, ReadOnly>,
+    tail: VolatilePtr<'a, u32, ReadWrite>,
+    data: VolatilePtr<'a, [T], WriteOnly>,
+    capacity: u32,
+    mode: IndexMode,
+}
+
+impl<T> Producer<'_, T>
+where
+    T: Copy,
+{
+    /// Returns the number of elements that are currently stored in the ring.
+    pub fn len(&self) -> usize {
+        self.mode
+            .len(load(self.head), load(self.tail), self.capacity) as usize
+    }
+
+    /// Returns whether the ring is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the ring is currently full.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.mode.usable(self.capacity) as usize
+    }
+
+    /// Appends the given element to the ring.
+    ///
+    /// Returns the element as error if the ring is full.
+    pub fn push(&self, value: T) -> Result<(), T> {
+        if self.push_slice(&[value]) == 1 {
+            Ok(())
+        } else {
+            Err(value)
+        }
+    }
+
+    /// Appends as many elements of `src` to the ring as possible.
+    ///
+    /// Returns the number of appended elements, which is smaller than `src.len()` if the ring
+    /// became full.
+    pub fn push_slice(&self, src: &[T]) -> usize {
+        let tail = load(self.tail);
+        // synchronizes with the consumer, so it finished reading the slots that we overwrite
+        let head = load(self.head);
+        let free = self.mode.usable(self.capacity) - self.mode.len(head, tail, self.capacity);
+        let count = src.len().min(free as usize);
+
+        for (i, value) in src[..count].iter().enumerate() {
+            let index = self.mode.advance(tail, i as u32, self.capacity);
+            let slot = self.mode.slot(index, self.capacity);
+            // SAFETY: `IndexMode::slot` always returns an in-bounds index
+            unsafe { self.data.element_unchecked(slot) }.write(*value);
+        }
+        // publishes the written slots to the consumer
+        store(
+            self.tail,
+            self.mode.advance(tail, count as u32, self.capacity),
+        );
+        count
+    }
+}
+
+/// The consuming half of a [`VolatileSpscRing`].
+pub struct Consumer<'a, T> {
+    head: VolatilePtr<'a, u32, ReadWrite>,
+    tail: VolatilePtr<'a, u32, ReadOnly>,
+    data: VolatilePtr<'a, [T], ReadOnly>,
+    capacity: u32,
+    mode: IndexMode,
+}
+
+impl<T> Consumer<'_, T>
+where
+    T: Copy,
+{
+    /// Returns the number of elements that are currently stored in the ring.
+    pub fn len(&self) -> usize {
+        self.mode
+            .len(load(self.head), load(self.tail), self.capacity) as usize
+    }
+
+    /// Returns whether the ring is currently empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes the oldest element from the ring.
+    ///
+    /// Returns `None` if the ring is empty.
+    pub fn pop(&self) -> Option<T> {
+        let head = load(self.head);
+        // synchronizes with the producer, so we see the data written before the index update
+        let tail = load(self.tail);
+        if self.mode.len(head, tail, self.capacity) == 0 {
+            return None;
+        }
+
+        let slot = self.mode.slot(head, self.capacity);
+        // SAFETY: `IndexMode::slot` always returns an in-bounds index
+        let value = unsafe { self.data.element_unchecked(slot) }.read();
+        // hands the slot back to the producer
+        store(self.head, self.mode.advance(head, 1, self.capacity));
+        Some(value)
+    }
+
+    /// Removes as many elements from the ring as fit into `dst`.
+    ///
+    /// Returns the number of removed elements, which is smaller than `dst.len()` if the ring
+    /// became empty.
+    pub fn pop_slice(&self, dst: &mut [T]) -> usize {
+        let head = load(self.head);
+        // synchronizes with the producer, so we see the data written before the index update
+        let tail = load(self.tail);
+        let count = dst
+            .len()
+            .min(self.mode.len(head, tail, self.capacity) as usize);
+
+        for (i, value) in dst[..count].iter_mut().enumerate() {
+            let index = self.mode.advance(head, i as u32, self.capacity);
+            let slot = self.mode.slot(index, self.capacity);
+            // SAFETY: `IndexMode::slot` always returns an in-bounds index
+            *value = unsafe { self.data.element_unchecked(slot) }.read();
+        }
+        // hands the slots back to the producer
+        store(
+            self.head,
+            self.mode.advance(head, count as u32, self.capacity),
+        );
+        count
+    }
+}
+
+/// Loads the given index atomically with acquire ordering.
+fn load<A>(index: VolatilePtr<'_, u32, A>) -> u32 {
+    // SAFETY: `new` checked that the index is aligned for `AtomicU32`, and the index is only
+    // accessed atomically
+    unsafe { AtomicU32::from_ptr(index.as_raw_ptr().as_ptr()) }.load(Ordering::Acquire)
+}
+
+/// Stores the given index atomically with release ordering.
+fn store(index: VolatilePtr<'_, u32, ReadWrite>, value: u32) {
+    // SAFETY: see `load`
+    unsafe { AtomicU32::from_ptr(index.as_raw_ptr().as_ptr()) }.store(value, Ordering::Release)
+}
+
+// SAFETY: each half only writes its own index and only reads the index of the other half. All
+// index accesses are atomic, and the acquire loads synchronize with the release stores of the
+// other half. So a data slot is only accessed by the half that currently owns it, and all
+// accesses of the previous owner happen before. Values of type `T` are moved between threads.
+unsafe impl<T> Send for Producer<'_, T> where T: Send {}
+
+// SAFETY: see the `Send` implementation of `Producer`.
+unsafe impl<T> Send for Consumer<'_, T> where T: Send {}
+
+#[cfg(test)]
+mod tests {
+    use super::{IndexMode, VolatileSpscRing};
+    use crate::VolatilePtr;
+    use core::ptr::NonNull;
+
+    fn check_wrap_around(mode: IndexMode, capacity: usize) {
+        let (mut head, mut tail) = (0u32, 0u32);
+        let mut data = [0u32; 4];
+        let ring = unsafe {
+            VolatileSpscRing::new(
+                VolatilePtr::new(NonNull::from(&mut head)),
+                VolatilePtr::new(NonNull::from(&mut tail)),
+                VolatilePtr::new(NonNull::from(&mut data[..])),
+                mode,
+            )
+        };
+        assert_eq!(ring.capacity(), capacity);
+        let (producer, consumer) = ring.split();
+
+        let mut next = 0;
+        for _ in 0..10 {
+            assert_eq!(producer.push_slice(&[next, next + 1, next + 2]), 3);
+            assert_eq!(producer.len(), 3);
+            let mut dst = [0; 2];
+            assert_eq!(consumer.pop_slice(&mut dst), 2);
+            assert_eq!(dst, [next, next + 1]);
+            assert_eq!(consumer.pop(), Some(next + 2));
+            assert!(consumer.is_empty());
+            next += 3;
+        }
+
+        for i in 0..capacity as u32 {
+            producer.push(i).unwrap();
+        }
+        assert!(producer.is_full());
+        assert_eq!(producer.push(42), Err(42));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        check_wrap_around(IndexMode::Wrapping, 3);
+    }
+
+    #[test]
+    fn test_power_of_two() {
+        check_wrap_around(IndexMode::PowerOfTwo, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wrapping_single_slot() {
+        let (mut head, mut tail) = (0u32, 0u32);
+        let mut data = [0u8; 1];
+        unsafe {
+            VolatileSpscRing::new(
+                VolatilePtr::new(NonNull::from(&mut head)),
+                VolatilePtr::new(NonNull::from(&mut tail)),
+                VolatilePtr::new(NonNull::from(&mut data[..])),
+                IndexMode::Wrapping,
+            )
+        };
+    }
+
+    #[test]
+    fn test_threads() {
+        extern crate std;
+
+        let (mut head, mut tail) = (0u32, 0u32);
+        let mut data = [0u64; 8];
+        let ring = unsafe {
+            VolatileSpscRing::new(
+                VolatilePtr::new(NonNull::from(&mut head)),
+                VolatilePtr::new(NonNull::from(&mut tail)),
+                VolatilePtr::new(NonNull::from(&mut data[..])),
+                IndexMode::PowerOfTwo,
+            )
+        };
+        let (producer, consumer) = ring.split();
+
+        std::thread::scope(|s| {
+            s.spawn(move || {
+                for i in 0..10_000 {
+                    while producer.push(i).is_err() {
+                        std::thread::yield_now();
+                    }
+                }
+            });
+            s.spawn(move || {
+                for i in 0..10_000 {
+                    let value = loop {
+                        match consumer.pop() {
+                            Some(value) => break value,
+                            None => std::thread::yield_now(),
+                        }
+                    };
+                    assert_eq!(value, i);
+                }
+            });
+        });
+    }
+
+    #[test]
+    fn test_power_of_two_counter_overflow() {
+        let (mut head, mut tail) = (u32::MAX - 1, u32::MAX - 1);
+        let mut data = [0u8; 2];
+        let ring = unsafe {
+            VolatileSpscRing::new(
+                VolatilePtr::new(NonNull::from(&mut head)),
+                VolatilePtr::new(NonNull::from(&mut tail)),
+                VolatilePtr::new(NonNull::from(&mut data[..])),
+                IndexMode::PowerOfTwo,
+            )
+        };
+        let (producer, consumer) = ring.split();
+        assert_eq!(producer.push_slice(&[1, 2]), 2);
+        assert_eq!(consumer.pop(), Some(1));
+        assert_eq!(producer.push(3), Ok(()));
+        assert_eq!(consumer.pop(), Some(2));
+        assert_eq!(consumer.pop(), Some(3));
+        assert_eq!((head, tail), (1, 1));
+    }
+}
@@ -0,0 +1,256 @@
+//! Lock-protected volatile values that can be shared between cores.
+//!
+//! A [`SharedVolatile`] owns a [`VolatileRef`] and only gives out access to it while holding a
+//! lock. This makes read-modify-write sequences such as [`VolatilePtr::update`] atomic with
+//! respect to other cores that access the value through the same `SharedVolatile`.
+//!
+//! The lock is pluggable through the [`RawLock`] trait. This module provides a [`SpinLock`]
+//! and, with the `critical-section` feature, a `CriticalSectionLock`.
+//!
+//! [`VolatilePtr::update`]: crate::VolatilePtr::update
+
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{
+    access::{Access, Readable, Writable},
+    VolatileRef,
+};
+
+/// A lock that provides mutual exclusion for [`SharedVolatile`].
+///
+/// ## Safety
+///
+/// Implementations must guarantee that no two calls of [`with_lock`][Self::with_lock] on the
+/// same lock execute their closures at the same time, including calls from other cores.
+pub unsafe trait RawLock {
+    /// Acquires the lock, runs `f`, and releases the lock again.
+    fn with_lock<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R;
+}
+
+/// A simple spinlock based on an [`AtomicBool`].
+///
+/// Waiting cores busy-loop until the lock is released. Note that the lock does not disable
+/// interrupts, so it must not be used to share values with interrupt handlers on the same core.
+#[cfg(target_has_atomic = "8")]
+#[derive(Debug, Default)]
+pub struct SpinLock {
+    locked: AtomicBool,
+}
+
+#[cfg(target_has_atomic = "8")]
+impl SpinLock {
+    /// Creates a new unlocked spinlock.
+    pub const fn new() -> Self {
+        SpinLock {
+            locked: AtomicBool::new(false),
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "8")]
+unsafe impl RawLock for SpinLock {
+    fn with_lock<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        struct Unlock<'a>(&'a AtomicBool);
+
+        impl Drop for Unlock<'_> {
+            fn drop(&mut self) {
+                self.0.store(false, Ordering::Release);
+            }
+        }
+
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        // release the lock even if `f` panics
+        let _unlock = Unlock(&self.locked);
+        f()
+    }
+}
+
+/// A lock that executes the closure in a [`critical_section`].
+///
+/// The lock only provides mutual exclusion between cores if the `critical-section`
+/// implementation of the target does, e.g. by using a hardware spinlock. Single-core
+/// implementations that only disable interrupts are sufficient for sharing values with
+/// interrupt handlers.
+#[cfg(feature = "critical-section")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CriticalSectionLock;
+
+#[cfg(feature = "critical-section")]
+unsafe impl RawLock for CriticalSectionLock {
+    fn with_lock<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        critical_section::with(|_| f())
+    }
+}
+
+/// A [`VolatileRef`] that is protected by a lock of type `L`.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{shared::{SharedVolatile, SpinLock}, VolatileRef};
+///
+/// let mut value = 0u32;
+/// let shared = SharedVolatile::new(VolatileRef::from_mut_ref(&mut value), SpinLock::new());
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| {
+///             for _ in 0..100 {
+///                 shared.update(|v| v + 1);
+///             }
+///         });
+///     }
+/// });
+///
+/// let total = shared.lock(|reference| reference.as_ptr().read());
+/// assert_eq!(total, 400);
+/// ```
+#[derive(Debug)]
+pub struct SharedVolatile<'a, T, A, L>
+where
+    T: ?Sized,
+{
+    reference: VolatileRef<'a, T, A>,
+    lock: L,
+}
+
+impl<'a, T, A, L> SharedVolatile<'a, T, A, L>
+where
+    T: ?Sized,
+{
+    /// Creates a new `SharedVolatile` that protects the given reference with the given lock.
+    pub const fn new(reference: VolatileRef<'a, T, A>, lock: L) -> Self {
+        SharedVolatile { reference, lock }
+    }
+
+    /// Returns the underlying `VolatileRef`.
+    pub fn into_inner(self) -> VolatileRef<'a, T, A> {
+        self.reference
+    }
+
+    /// Returns a mutable reference to the underlying `VolatileRef`.
+    ///
+    /// No locking is needed since the exclusive borrow statically guarantees that no other
+    /// code can access the value.
+    pub fn get_mut(&mut self) -> &mut VolatileRef<'a, T, A> {
+        &mut self.reference
+    }
+}
+
+impl<T, A, L> SharedVolatile<'_, T, A, L>
+where
+    T: ?Sized,
+    A: Access,
+    L: RawLock,
+{
+    /// Acquires the lock and passes a guard `VolatileRef` to the given closure.
+    ///
+    /// The guard can't escape the closure, so all accesses through it happen while the lock
+    /// is held.
+    pub fn lock<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(VolatileRef<'_, T, A>) -> R,
+    {
+        self.lock.with_lock(|| {
+            // SAFETY: the lock ensures that no other guard exists at the same time
+            let guard =
+                unsafe { VolatileRef::new_restricted(A::default(), self.reference.as_raw_ptr()) };
+            f(guard)
+        })
+    }
+
+    /// Updates the value while holding the lock.
+    ///
+    /// See [`VolatilePtr::update`][crate::VolatilePtr::update] for details.
+    pub fn update<F>(&self, f: F)
+    where
+        T: Sized + Copy,
+        A: Readable + Writable,
+        F: FnOnce(T) -> T,
+    {
+        self.lock(|mut guard| guard.as_mut_ptr().update(f))
+    }
+
+    /// Reads the value while holding the lock.
+    pub fn read(&self) -> T
+    where
+        T: Sized + Copy,
+        A: Readable,
+    {
+        self.lock(|mut guard| guard.as_mut_ptr().read())
+    }
+
+    /// Writes the value while holding the lock.
+    pub fn write(&self, value: T)
+    where
+        T: Sized + Copy,
+        A: Writable,
+    {
+        self.lock(|mut guard| guard.as_mut_ptr().write(value))
+    }
+}
+
+// SAFETY: all accesses through a shared reference are serialized by the lock, so the
+// `SharedVolatile` behaves like a `Mutex`.
+unsafe impl<T, A, L> Sync for SharedVolatile<'_, T, A, L>
+where
+    T: Send + ?Sized,
+    L: RawLock + Sync,
+{
+}
+
+// SAFETY: `SharedVolatile` owns the only reference to the value.
+unsafe impl<T, A, L> Send for SharedVolatile<'_, T, A, L>
+where
+    T: Send + ?Sized,
+    L: Send,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RawLock, SharedVolatile, SpinLock};
+    use crate::VolatileRef;
+
+    #[test]
+    fn test_lock_released_on_panic() {
+        extern crate std;
+
+        let lock = SpinLock::new();
+        let result = std::panic::catch_unwind(|| lock.with_lock(|| panic!("poisoned")));
+        assert!(result.is_err());
+        assert_eq!(lock.with_lock(|| 1), 1);
+
+        let mut value = [1u8, 2];
+        let shared = SharedVolatile::new(VolatileRef::from_mut_ref(&mut value), lock);
+        shared.write([3, 4]);
+        assert_eq!(shared.read(), [3, 4]);
+    }
+
+    #[cfg(feature = "critical-section")]
+    #[test]
+    fn test_critical_section_lock() {
+        use super::CriticalSectionLock;
+
+        let mut value = 1u32;
+        let shared =
+            SharedVolatile::new(VolatileRef::from_mut_ref(&mut value), CriticalSectionLock);
+        shared.update(|v| v * 2);
+        assert_eq!(shared.read(), 2);
+    }
+}
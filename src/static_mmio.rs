@@ -0,0 +1,189 @@
+//! Singletons for memory-mapped devices at fixed addresses.
+//!
+//! The [`static_mmio!`][crate::static_mmio!] macro declares a `static` [`StaticMmio`] item for a
+//! device at a fixed address. The [`StaticMmio::take`] method hands out the `'static`
+//! [`VolatileRef`] to the device exactly once, so that drivers can rely on exclusive access
+//! without any further synchronization.
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use crate::{
+    access::{Access, ReadWrite},
+    VolatileRef,
+};
+
+/// A [`Sync`] holder for a [`VolatileRef`] to a `T` value at a fixed address.
+///
+/// Values of this type are typically declared through the [`static_mmio!`][crate::static_mmio!]
+/// macro.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::static_mmio::StaticMmio;
+///
+/// struct UartRegs { data: u32, status: u32 }
+/// # let regs = Box::leak(Box::new(UartRegs { data: 0, status: 0 }));
+/// # let addr = regs as *mut UartRegs as usize;
+///
+/// let uart: StaticMmio<UartRegs> = unsafe { StaticMmio::new(addr) };
+/// let regs = uart.take().unwrap();
+/// assert!(uart.take().is_none());
+/// ```
+pub struct StaticMmio<T, A = ReadWrite> {
+    addr: usize,
+    taken: AtomicBool,
+    value: PhantomData<fn() -> (T, A)>,
+}
+
+impl<T, A> StaticMmio<T, A>
+where
+    A: Access,
+{
+    /// Creates a holder for the `T` value at the given memory address.
+    ///
+    /// ## Safety
+    ///
+    /// - The address must be non-zero and properly aligned for `T`.
+    /// - The memory at the given address must be valid for all operations allowed by `A` for
+    ///   the whole program runtime.
+    /// - No other code must access the memory at the given address, except through this
+    ///   `StaticMmio`. In particular, there must be only one `StaticMmio` for each address.
+    pub const unsafe fn new(addr: usize) -> Self {
+        StaticMmio {
+            addr,
+            taken: AtomicBool::new(false),
+            value: PhantomData,
+        }
+    }
+
+    /// Returns the address of the held value.
+    pub const fn addr(&self) -> usize {
+        self.addr
+    }
+
+    /// Returns the `VolatileRef` to the held value if it was not taken before.
+    ///
+    /// This method returns `Some` at most once. All later calls return `None`, including
+    /// calls from other threads.
+    ///
+    /// On targets without atomic swap operations (e.g. `thumbv6m`), this method requires the
+    /// `critical-section` feature. Without it, use [`steal`][Self::steal] and make sure that it
+    /// is only called once.
+    #[cfg(any(target_has_atomic = "8", feature = "critical-section"))]
+    pub fn take(&self) -> Option<VolatileRef<'static, T, A>> {
+        if self.mark_taken() {
+            None
+        } else {
+            Some(unsafe { VolatileRef::from_addr_restricted(A::default(), self.addr) })
+        }
+    }
+
+    /// Marks the value as taken and returns whether it was taken before.
+    #[cfg(target_has_atomic = "8")]
+    fn mark_taken(&self) -> bool {
+        self.taken.swap(true, Ordering::AcqRel)
+    }
+
+    /// Marks the value as taken and returns whether it was taken before.
+    #[cfg(all(not(target_has_atomic = "8"), feature = "critical-section"))]
+    fn mark_taken(&self) -> bool {
+        critical_section::with(|_| {
+            let taken = self.taken.load(Ordering::Acquire);
+            self.taken.store(true, Ordering::Release);
+            taken
+        })
+    }
+
+    /// Returns the `VolatileRef` to the held value, regardless of whether it was taken before.
+    ///
+    /// The value is marked as taken, so later calls to [`take`][Self::take] return `None`.
+    ///
+    /// ## Safety
+    ///
+    /// Unless `A` only allows reads, no other `VolatileRef` to the held value must be used
+    /// while the returned one exists.
+    pub unsafe fn steal(&self) -> VolatileRef<'static, T, A> {
+        self.taken.store(true, Ordering::Release);
+        unsafe { VolatileRef::from_addr_restricted(A::default(), self.addr) }
+    }
+}
+
+impl<T, A> fmt::Debug for StaticMmio<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticMmio")
+            .field("addr", &format_args!("{:#x}", self.addr))
+            .field("taken", &self.taken.load(Ordering::Relaxed))
+            .finish()
+    }
+}
+
+/// Declares a `static` [`StaticMmio`][crate::static_mmio::StaticMmio] singleton for a
+/// memory-mapped device at a fixed address.
+///
+/// The syntax is `static_mmio!(unsafe NAME: Type = address, Access)`, where `Type` can be any
+/// type, including paths, arrays, and generic types. The access type is optional and defaults to [`ReadWrite`][crate::access::ReadWrite]. Attributes and a visibility
+/// can be specified before the `unsafe` keyword.
+///
+/// ## Safety
+///
+/// The `unsafe` keyword is required because the macro invocation asserts that the
+/// requirements of [`StaticMmio::new`][crate::static_mmio::StaticMmio::new] are fulfilled.
+///
+/// ## Example
+///
+/// ```no_run
+/// use volatile::{access::{ReadOnly, ReadWrite}, map_field, static_mmio};
+///
+/// #[repr(C)]
+/// pub struct UartRegs { data: u32, status: u32 }
+///
+/// static_mmio!(unsafe UART0: UartRegs = 0x1000_0000, ReadWrite);
+/// static_mmio!(pub(crate) unsafe BOOT_ID: u32 = 0x2000_0000, ReadOnly);
+/// static_mmio!(unsafe SCRATCH: [u32; 4] = 0x3000_0000);
+///
+/// let mut uart = UART0.take().unwrap();
+/// let mut data = map_field!(&mut uart.data);
+/// data.as_mut_ptr().write(b'a'.into());
+/// let boot_id = BOOT_ID.take().unwrap().as_ptr().read();
+/// let scratch = SCRATCH.take().unwrap().as_ptr().read();
+/// ```
+#[macro_export]
+macro_rules! static_mmio {
+    (
+        $(#[$attr:meta])* $vis:vis unsafe $name:ident : $ty:ty = $addr:expr
+    ) => {
+        $crate::static_mmio!(
+            $(#[$attr])* $vis unsafe $name : $ty = $addr, $crate::access::ReadWrite
+        );
+    };
+    (
+        $(#[$attr:meta])* $vis:vis unsafe $name:ident : $ty:ty = $addr:expr, $access:ty
+    ) => {
+        $(#[$attr])*
+        $vis static $name: $crate::static_mmio::StaticMmio<$ty, $access> =
+            unsafe { $crate::static_mmio::StaticMmio::new($addr) };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StaticMmio;
+    use crate::access::ReadOnly;
+
+    #[test]
+    fn test_take_once() {
+        static mut VALUE: u64 = 42;
+
+        let addr = core::ptr::addr_of_mut!(VALUE) as usize;
+        let mmio: StaticMmio<u64, ReadOnly> = unsafe { StaticMmio::new(addr) };
+        let value = mmio.take().unwrap();
+        assert_eq!(value.as_ptr().read(), 42);
+        assert!(mmio.take().is_none());
+        assert_eq!(unsafe { mmio.steal() }.as_ptr().read(), 42);
+    }
+}
@@ -0,0 +1,245 @@
+//! Memory shared between processes through `memfd` or POSIX shared memory objects.
+//!
+//! This module is only available with the `unix` feature. It requires the standard library.
+//!
+//! The [`SharedMapping`] type creates or opens a shared memory object, maps it into the address
+//! space of the process, and unmaps it again on drop. The volatile pointers that it hands out
+//! borrow the mapping, so they can't outlive it.
+//!
+//! ## Example
+//!
+//! ```
+//! # #[cfg(any(target_os = "linux", target_os = "android"))]
+//! # fn main() -> std::io::Result<()> {
+//! use volatile::unix::SharedMapping;
+//!
+//! let mut mapping = SharedMapping::memfd("ipc", 4096)?;
+//! // pass `mapping.as_fd()` to another process, e.g. through a Unix socket
+//!
+//! let mut counter = mapping.ref_at::<u32>(0).unwrap();
+//! counter.as_mut_ptr().write(1);
+//! assert_eq!(counter.as_ptr().read(), 1);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(any(target_os = "linux", target_os = "android")))]
+//! # fn main() {}
+//! ```
+
+extern crate std;
+
+use core::{mem, ptr::NonNull};
+use std::{
+    ffi::CString,
+    io,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd},
+};
+
+use crate::{bit_pattern::AnyBitPattern, VolatilePtr, VolatileRef};
+
+/// A shared memory object that is mapped into the address space of this process.
+///
+/// The mapping is readable and writable and uses `MAP_SHARED`, so writes are visible to all
+/// processes that map the same object. It is unmapped when the `SharedMapping` is dropped.
+#[derive(Debug)]
+pub struct SharedMapping {
+    pointer: NonNull<u8>,
+    len: usize,
+    fd: OwnedFd,
+}
+
+impl SharedMapping {
+    /// Creates an anonymous shared memory object of `len` bytes through `memfd_create` and maps
+    /// it.
+    ///
+    /// The `name` is only used for debugging purposes. Other processes can map the object
+    /// through [`from_fd`][Self::from_fd] after receiving the file descriptor.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn memfd(name: &str, len: usize) -> io::Result<Self> {
+        let name = c_string(name)?;
+        let fd = unsafe { libc::memfd_create(name.as_ptr(), libc::MFD_CLOEXEC) };
+        let fd = owned_fd(fd)?;
+        set_len(&fd, len)?;
+        Self::map(fd, len)
+    }
+
+    /// Creates a new POSIX shared memory object of `len` bytes through `shm_open` and maps it.
+    ///
+    /// The `name` must start with a slash and must not exist yet. The object persists until it
+    /// is removed through [`unlink_shm`][Self::unlink_shm].
+    pub fn create_shm(name: &str, len: usize) -> io::Result<Self> {
+        let name = c_string(name)?;
+        let flags = libc::O_RDWR | libc::O_CREAT | libc::O_EXCL | libc::O_CLOEXEC;
+        let fd = unsafe { libc::shm_open(name.as_ptr(), flags, 0o600) };
+        let fd = owned_fd(fd)?;
+        set_len(&fd, len)?;
+        Self::map(fd, len)
+    }
+
+    /// Opens an existing POSIX shared memory object through `shm_open` and maps it completely.
+    pub fn open_shm(name: &str) -> io::Result<Self> {
+        let name = c_string(name)?;
+        let fd = unsafe { libc::shm_open(name.as_ptr(), libc::O_RDWR | libc::O_CLOEXEC, 0) };
+        Self::from_fd(owned_fd(fd)?)
+    }
+
+    /// Removes the POSIX shared memory object with the given name.
+    ///
+    /// Existing mappings stay valid until they are dropped.
+    pub fn unlink_shm(name: &str) -> io::Result<()> {
+        let name = c_string(name)?;
+        if unsafe { libc::shm_unlink(name.as_ptr()) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Maps the complete shared memory object behind the given file descriptor.
+    pub fn from_fd(fd: OwnedFd) -> io::Result<Self> {
+        let mut stat = mem::MaybeUninit::<libc::stat>::uninit();
+        if unsafe { libc::fstat(fd.as_raw_fd(), stat.as_mut_ptr()) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        let len = unsafe { stat.assume_init() }.st_size;
+        let len = usize::try_from(len).map_err(|_| io::ErrorKind::InvalidData)?;
+        Self::map(fd, len)
+    }
+
+    fn map(fd: OwnedFd, len: usize) -> io::Result<Self> {
+        if len == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "cannot map an empty shared memory object",
+            ));
+        }
+        let pointer = unsafe {
+            libc::mmap(
+                core::ptr::null_mut(),
+                len,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_SHARED,
+                fd.as_raw_fd(),
+                0,
+            )
+        };
+        if pointer == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        let pointer = NonNull::new(pointer.cast()).ok_or(io::ErrorKind::Other)?;
+        Ok(SharedMapping { pointer, len, fd })
+    }
+
+    /// Returns the size of the mapping in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the mapping has a size of zero bytes.
+    ///
+    /// This is never the case since empty objects can't be mapped.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a `VolatilePtr` to the complete mapping.
+    pub fn as_slice(&self) -> VolatilePtr<'_, [u8]> {
+        let slice = NonNull::slice_from_raw_parts(self.pointer, self.len);
+        unsafe { VolatilePtr::new(slice) }
+    }
+
+    /// Returns a `VolatileRef` to the `T` value at the given byte offset.
+    ///
+    /// Returns `None` if the value doesn't fit into the mapping or if the resulting address is
+    /// not properly aligned for `T`. The memory might be modified by other processes at any
+    /// time, so `T` must be valid for any bit pattern.
+    pub fn ref_at<T>(&mut self, offset: usize) -> Option<VolatileRef<'_, T>>
+    where
+        T: AnyBitPattern,
+    {
+        let end = offset.checked_add(mem::size_of::<T>())?;
+        if end > self.len {
+            return None;
+        }
+        // SAFETY: we checked that the offset is in bounds
+        let pointer = unsafe { self.pointer.add(offset) }.cast::<T>();
+        if !pointer.as_ptr().is_aligned() {
+            return None;
+        }
+        Some(unsafe { VolatileRef::new(pointer) })
+    }
+}
+
+impl AsFd for SharedMapping {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl Drop for SharedMapping {
+    fn drop(&mut self) {
+        unsafe { libc::munmap(self.pointer.as_ptr().cast(), self.len) };
+    }
+}
+
+// SAFETY: the mapping is owned by the `SharedMapping` and can be unmapped from any thread.
+unsafe impl Send for SharedMapping {}
+
+fn c_string(name: &str) -> io::Result<CString> {
+    CString::new(name).map_err(|_| io::ErrorKind::InvalidInput.into())
+}
+
+fn owned_fd(fd: libc::c_int) -> io::Result<OwnedFd> {
+    if fd == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+fn set_len(fd: &OwnedFd, len: usize) -> io::Result<()> {
+    let len = libc::off_t::try_from(len).map_err(|_| io::ErrorKind::InvalidInput)?;
+    if unsafe { libc::ftruncate(fd.as_raw_fd(), len) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::SharedMapping;
+    use std::{format, os::fd::AsFd, process};
+
+    #[test]
+    fn test_shm() {
+        let name = format!("/volatile-test-{}", process::id());
+        let mut writer = SharedMapping::create_shm(&name, 64).unwrap();
+        let reader = SharedMapping::open_shm(&name).unwrap();
+        SharedMapping::unlink_shm(&name).unwrap();
+        assert_eq!(reader.len(), 64);
+
+        let mut value = writer.ref_at::<u64>(8).unwrap();
+        value.as_mut_ptr().write(0x0123_4567_89ab_cdef);
+        let bytes = reader.as_slice();
+        let byte = unsafe { bytes.element_unchecked(8) }.read();
+        assert_eq!(byte, 0x0123_4567_89ab_cdef_u64.to_ne_bytes()[0]);
+
+        assert!(writer.ref_at::<u64>(60).is_none());
+        assert!(writer.ref_at::<u64>(4).is_none());
+        assert!(writer.ref_at::<u64>(usize::MAX).is_none());
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn test_memfd() {
+        let mapping = SharedMapping::memfd("volatile-test", 16).unwrap();
+        let fd = mapping.as_fd().try_clone_to_owned().unwrap();
+        let mut second = SharedMapping::from_fd(fd).unwrap();
+        second
+            .ref_at::<[u8; 4]>(0)
+            .unwrap()
+            .as_mut_ptr()
+            .write(*b"ping");
+        let first = mapping.as_slice();
+        assert_eq!(unsafe { first.element_unchecked(3) }.read(), b'g');
+    }
+}
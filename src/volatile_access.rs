@@ -0,0 +1,180 @@
+use core::ptr::NonNull;
+
+use crate::{
+    access::{Access, Readable, RestrictTo, Writable},
+    VolatilePtr, VolatileRef,
+};
+
+/// Common interface of [`VolatilePtr`] and [`VolatileRef`].
+///
+/// The lifetime `'a` is the lifetime of the wrapper types, which is kept by
+/// [`map`][Self::map] and [`restrict`][Self::restrict].
+///
+/// Driver code that is generic over this trait works with both wrapper types. The trait can
+/// also be implemented by other types, e.g. by mocks that record all accesses in tests.
+///
+/// Like for `VolatileRef`, writes require `&mut self`. For `VolatilePtr`, the methods behave
+/// exactly like the inherent methods of the same name.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{access::{Readable, Writable}, VolatileAccess, VolatilePtr, VolatileRef};
+/// use core::ptr::NonNull;
+///
+/// fn increment<'a, V>(register: &mut V)
+/// where
+///     V: VolatileAccess<'a, Target = u32>,
+///     V::Access: Readable + Writable,
+/// {
+///     register.update(|value| value + 1);
+/// }
+///
+/// let mut value = 0;
+/// let mut volatile = unsafe { VolatilePtr::new(NonNull::from(&mut value)) };
+/// increment(&mut volatile);
+///
+/// let mut volatile = VolatileRef::from_mut_ref(&mut value);
+/// increment(&mut volatile);
+/// assert_eq!(value, 2);
+/// ```
+pub trait VolatileAccess<'a> {
+    /// The type of the referenced value.
+    type Target: ?Sized;
+
+    /// The access type, e.g. [`ReadWrite`][crate::access::ReadWrite].
+    type Access: Access;
+
+    /// The same wrapper type with a different target and access type.
+    type Mapped<U: ?Sized + 'a, B: Access>: VolatileAccess<'a, Target = U, Access = B>;
+
+    /// Performs a volatile read of the referenced value.
+    fn read(&self) -> Self::Target
+    where
+        Self::Target: Copy,
+        Self::Access: Readable;
+
+    /// Performs a volatile write, setting the referenced value to the given `value`.
+    fn write(&mut self, value: Self::Target)
+    where
+        Self::Target: Copy,
+        Self::Access: Writable;
+
+    /// Updates the referenced value using the given closure.
+    ///
+    /// Performs a volatile read, passes the value to `f`, and performs a volatile write of the
+    /// returned value.
+    fn update<F>(&mut self, f: F)
+    where
+        Self::Target: Copy,
+        Self::Access: Readable + Writable,
+        F: FnOnce(Self::Target) -> Self::Target,
+    {
+        let new = f(self.read());
+        self.write(new);
+    }
+
+    /// Creates a new wrapper by mapping the underlying pointer.
+    ///
+    /// ## Safety
+    ///
+    /// The pointer returned by `f` must satisfy the requirements of the constructors of the
+    /// wrapper type, e.g. [`VolatilePtr::new`].
+    unsafe fn map<F, U>(self, f: F) -> Self::Mapped<U, Self::Access>
+    where
+        Self: Sized,
+        F: FnOnce(NonNull<Self::Target>) -> NonNull<U>,
+        U: ?Sized + 'a;
+
+    /// Restricts access permissions to the given access type `B`.
+    fn restrict<B>(self) -> Self::Mapped<Self::Target, B>
+    where
+        Self: Sized,
+        Self::Access: RestrictTo<B>,
+        B: Access;
+}
+
+impl<'a, T, A> VolatileAccess<'a> for VolatilePtr<'a, T, A>
+where
+    T: ?Sized,
+    A: Access,
+{
+    type Target = T;
+    type Access = A;
+    type Mapped<U: ?Sized + 'a, B: Access> = VolatilePtr<'a, U, B>;
+
+    fn read(&self) -> T
+    where
+        T: Copy,
+        A: Readable,
+    {
+        VolatilePtr::read(*self)
+    }
+
+    fn write(&mut self, value: T)
+    where
+        T: Copy,
+        A: Writable,
+    {
+        VolatilePtr::write(*self, value)
+    }
+
+    unsafe fn map<F, U>(self, f: F) -> VolatilePtr<'a, U, A>
+    where
+        F: FnOnce(NonNull<T>) -> NonNull<U>,
+        U: ?Sized + 'a,
+    {
+        unsafe { VolatilePtr::map(self, f) }
+    }
+
+    fn restrict<B>(self) -> VolatilePtr<'a, T, B>
+    where
+        A: RestrictTo<B>,
+        B: Access,
+    {
+        VolatilePtr::restrict(self)
+    }
+}
+
+impl<'a, T, A> VolatileAccess<'a> for VolatileRef<'a, T, A>
+where
+    T: ?Sized,
+    A: Access,
+{
+    type Target = T;
+    type Access = A;
+    type Mapped<U: ?Sized + 'a, B: Access> = VolatileRef<'a, U, B>;
+
+    fn read(&self) -> T
+    where
+        T: Copy,
+        A: Readable,
+    {
+        // SAFETY: the pointer is only used for a read while `self` is borrowed
+        unsafe { VolatilePtr::new_restricted(A::default(), self.as_raw_ptr()) }.read()
+    }
+
+    fn write(&mut self, value: T)
+    where
+        T: Copy,
+        A: Writable,
+    {
+        self.as_mut_ptr().write(value)
+    }
+
+    unsafe fn map<F, U>(self, f: F) -> VolatileRef<'a, U, A>
+    where
+        F: FnOnce(NonNull<T>) -> NonNull<U>,
+        U: ?Sized + 'a,
+    {
+        unsafe { VolatileRef::map(self, f) }
+    }
+
+    fn restrict<B>(self) -> VolatileRef<'a, T, B>
+    where
+        A: RestrictTo<B>,
+        B: Access,
+    {
+        VolatileRef::restrict(self)
+    }
+}
@@ -0,0 +1,302 @@
+//! Volatile accesses through inline assembly.
+//!
+//! `ptr::read_volatile` and `ptr::write_volatile` don't guarantee that the access is performed
+//! by a single instruction of the full width. On aarch64, LLVM might also emit load/store pair
+//! instructions or addressing modes with writeback, which can't be emulated by hypervisors for
+//! MMIO regions. The functions in this module emit exactly one plain load or store instruction
+//! for [`NoTear`] values and fall back to the `core::ptr` functions on other architectures.
+//!
+//! Only `NoTear` types are passed through the assembly since they are plain integers without
+//! padding bytes. Transmuting arbitrary `Copy` types to integers would read uninitialized
+//! padding, which is undefined behavior.
+
+use core::{mem, ptr};
+
+use crate::width::NoTear;
+
+/// Performs a volatile read, using a single load instruction if possible.
+///
+/// ## Safety
+///
+/// Same requirements as [`ptr::read_volatile`].
+pub(crate) unsafe fn read_volatile<T>(src: *const T) -> T
+where
+    T: NoTear,
+{
+    debug_assert!(src.is_aligned());
+    // SAFETY: `NoTear` types are integers of 1, 2, 4, or 8 bytes without padding, so they can
+    // be transmuted from integers of the same size. The caller guarantees that `src` is aligned
+    // and valid for reads.
+    unsafe {
+        match mem::size_of::<T>() {
+            1 => mem::transmute_copy(&arch::load8(src.cast())),
+            2 => mem::transmute_copy(&arch::load16(src.cast())),
+            4 => mem::transmute_copy(&arch::load32(src.cast())),
+            8 => mem::transmute_copy(&arch::load64(src.cast())),
+            _ => ptr::read_volatile(src),
+        }
+    }
+}
+
+/// Performs a volatile write, using a single store instruction if possible.
+///
+/// ## Safety
+///
+/// Same requirements as [`ptr::write_volatile`].
+pub(crate) unsafe fn write_volatile<T>(dst: *mut T, value: T)
+where
+    T: NoTear,
+{
+    debug_assert!(dst.is_aligned());
+    // SAFETY: `NoTear` types are integers of 1, 2, 4, or 8 bytes without padding, so all bytes
+    // of `value` are initialized. The caller guarantees that `dst` is aligned and valid for
+    // writes.
+    unsafe {
+        match mem::size_of::<T>() {
+            1 => arch::store8(dst.cast(), mem::transmute_copy(&value)),
+            2 => arch::store16(dst.cast(), mem::transmute_copy(&value)),
+            4 => arch::store32(dst.cast(), mem::transmute_copy(&value)),
+            8 => arch::store64(dst.cast(), mem::transmute_copy(&value)),
+            _ => ptr::write_volatile(dst, value),
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod arch {
+    use core::arch::asm;
+
+    pub unsafe fn load8(src: *const u8) -> u8 {
+        let value;
+        unsafe {
+            asm!(
+                "mov {}, byte ptr [{}]",
+                out(reg_byte) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn load16(src: *const u16) -> u16 {
+        let value;
+        unsafe {
+            asm!(
+                "mov {:x}, word ptr [{}]",
+                out(reg) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn load32(src: *const u32) -> u32 {
+        let value;
+        unsafe {
+            asm!(
+                "mov {:e}, dword ptr [{}]",
+                out(reg) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn load64(src: *const u64) -> u64 {
+        let value;
+        unsafe {
+            asm!(
+                "mov {:r}, qword ptr [{}]",
+                out(reg) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn store8(dst: *mut u8, value: u8) {
+        unsafe {
+            asm!(
+                "mov byte ptr [{}], {}",
+                in(reg) dst,
+                in(reg_byte) value,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+
+    pub unsafe fn store16(dst: *mut u16, value: u16) {
+        unsafe {
+            asm!(
+                "mov word ptr [{}], {:x}",
+                in(reg) dst,
+                in(reg) value,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+
+    pub unsafe fn store32(dst: *mut u32, value: u32) {
+        unsafe {
+            asm!(
+                "mov dword ptr [{}], {:e}",
+                in(reg) dst,
+                in(reg) value,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+
+    pub unsafe fn store64(dst: *mut u64, value: u64) {
+        unsafe {
+            asm!(
+                "mov qword ptr [{}], {:r}",
+                in(reg) dst,
+                in(reg) value,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod arch {
+    use core::arch::asm;
+
+    pub unsafe fn load8(src: *const u8) -> u8 {
+        let value;
+        unsafe {
+            asm!(
+                "ldrb {:w}, [{}]",
+                out(reg) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn load16(src: *const u16) -> u16 {
+        let value;
+        unsafe {
+            asm!(
+                "ldrh {:w}, [{}]",
+                out(reg) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn load32(src: *const u32) -> u32 {
+        let value;
+        unsafe {
+            asm!(
+                "ldr {:w}, [{}]",
+                out(reg) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn load64(src: *const u64) -> u64 {
+        let value;
+        unsafe {
+            asm!(
+                "ldr {:x}, [{}]",
+                out(reg) value,
+                in(reg) src,
+                options(nostack, preserves_flags),
+            );
+        }
+        value
+    }
+
+    pub unsafe fn store8(dst: *mut u8, value: u8) {
+        unsafe {
+            asm!(
+                "strb {:w}, [{}]",
+                in(reg) value,
+                in(reg) dst,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+
+    pub unsafe fn store16(dst: *mut u16, value: u16) {
+        unsafe {
+            asm!(
+                "strh {:w}, [{}]",
+                in(reg) value,
+                in(reg) dst,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+
+    pub unsafe fn store32(dst: *mut u32, value: u32) {
+        unsafe {
+            asm!(
+                "str {:w}, [{}]",
+                in(reg) value,
+                in(reg) dst,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+
+    pub unsafe fn store64(dst: *mut u64, value: u64) {
+        unsafe {
+            asm!(
+                "str {:x}, [{}]",
+                in(reg) value,
+                in(reg) dst,
+                options(nostack, preserves_flags),
+            );
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod arch {
+    //! Fallback for architectures without inline assembly support in this crate.
+
+    pub unsafe fn load8(src: *const u8) -> u8 {
+        unsafe { core::ptr::read_volatile(src) }
+    }
+
+    pub unsafe fn load16(src: *const u16) -> u16 {
+        unsafe { core::ptr::read_volatile(src) }
+    }
+
+    pub unsafe fn load32(src: *const u32) -> u32 {
+        unsafe { core::ptr::read_volatile(src) }
+    }
+
+    pub unsafe fn load64(src: *const u64) -> u64 {
+        unsafe { core::ptr::read_volatile(src) }
+    }
+
+    pub unsafe fn store8(dst: *mut u8, value: u8) {
+        unsafe { core::ptr::write_volatile(dst, value) }
+    }
+
+    pub unsafe fn store16(dst: *mut u16, value: u16) {
+        unsafe { core::ptr::write_volatile(dst, value) }
+    }
+
+    pub unsafe fn store32(dst: *mut u32, value: u32) {
+        unsafe { core::ptr::write_volatile(dst, value) }
+    }
+
+    pub unsafe fn store64(dst: *mut u64, value: u64) {
+        unsafe { core::ptr::write_volatile(dst, value) }
+    }
+}
@@ -0,0 +1,96 @@
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl};
+
+use super::VolatilePtr;
+use crate::access::{Readable, Writable};
+
+/// Bit manipulation methods for integer values.
+///
+/// The methods are available for all types that support the required bit operations, in
+/// particular all primitive integer types. The modifying methods perform a volatile read
+/// followed by a volatile write through [`update`][VolatilePtr::update], so they are not atomic.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::VolatilePtr;
+/// use core::ptr::NonNull;
+///
+/// let mut value = 0b0000_1111u8;
+/// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+///
+/// volatile.set_bits(0b0011_0000);
+/// volatile.clear_bits(0b0000_0011);
+/// volatile.toggle_bits(0b1000_0001);
+/// assert_eq!(volatile.read(), 0b1011_1101);
+///
+/// volatile.write_bits(0b1111_0000, 0b0101_1010);
+/// assert_eq!(volatile.read(), 0b0101_1101);
+/// assert!(volatile.test_bit(6));
+/// assert!(volatile.test_all(0b0101_0000));
+/// assert!(!volatile.test_all(0b0110_0000));
+/// ```
+impl<T, A> VolatilePtr<'_, T, A>
+where
+    T: Copy,
+{
+    /// Sets all bits that are set in `mask`.
+    pub fn set_bits(self, mask: T)
+    where
+        T: BitOr<Output = T>,
+        A: Readable + Writable,
+    {
+        self.update(|value| value | mask);
+    }
+
+    /// Clears all bits that are set in `mask`.
+    pub fn clear_bits(self, mask: T)
+    where
+        T: BitAnd<Output = T> + Not<Output = T>,
+        A: Readable + Writable,
+    {
+        self.update(|value| value & !mask);
+    }
+
+    /// Inverts all bits that are set in `mask`.
+    pub fn toggle_bits(self, mask: T)
+    where
+        T: BitXor<Output = T>,
+        A: Readable + Writable,
+    {
+        self.update(|value| value ^ mask);
+    }
+
+    /// Replaces the bits that are set in `mask` with the corresponding bits of `bits`.
+    ///
+    /// All bits that are not set in `mask` keep their current value.
+    pub fn write_bits(self, mask: T, bits: T)
+    where
+        T: BitAnd<Output = T> + BitOr<Output = T> + Not<Output = T>,
+        A: Readable + Writable,
+    {
+        self.update(|value| (value & !mask) | (bits & mask));
+    }
+
+    /// Returns whether the bit with index `n` is set, counting from the least significant bit.
+    ///
+    /// ## Panics
+    ///
+    /// Might panic if `n` is not smaller than the number of bits of `T`, like the `<<`
+    /// operator.
+    pub fn test_bit(self, n: u32) -> bool
+    where
+        T: BitAnd<Output = T> + Shl<u32, Output = T> + From<bool> + PartialEq,
+        A: Readable,
+    {
+        self.read() & (T::from(true) << n) != T::from(false)
+    }
+
+    /// Returns whether all bits that are set in `mask` are set.
+    pub fn test_all(self, mask: T) -> bool
+    where
+        T: BitAnd<Output = T> + PartialEq,
+        A: Readable,
+    {
+        self.read() & mask == mask
+    }
+}
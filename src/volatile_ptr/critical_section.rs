@@ -0,0 +1,82 @@
+use core::ops::{BitAnd, BitOr, Not};
+
+use super::VolatilePtr;
+use crate::access::{Readable, Writable};
+
+/// Interrupt-safe read-modify-write operations, available with the `critical-section` feature.
+///
+/// These methods perform the read and the write inside a [`critical_section::with`] block, so
+/// that no interrupt handler can modify the value in between. On multi-core targets, they are
+/// only atomic with respect to other cores if the `critical-section` implementation of the
+/// target provides mutual exclusion between cores.
+impl<T, A> VolatilePtr<'_, T, A>
+where
+    T: Copy,
+    A: Readable + Writable,
+{
+    /// Updates the contained value using the given closure inside a critical section.
+    ///
+    /// Works like [`update`][VolatilePtr::update], but no interrupt can occur between the
+    /// read and the write.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 42;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    /// volatile.update_cs(|val| val + 1);
+    ///
+    /// assert_eq!(volatile.read(), 43);
+    /// ```
+    pub fn update_cs<F>(self, f: F)
+    where
+        F: FnOnce(T) -> T,
+    {
+        critical_section::with(|_| self.update(f))
+    }
+
+    /// Sets the given `bits` inside a critical section.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 0b0101u8;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    /// volatile.set_bits_cs(0b0011);
+    ///
+    /// assert_eq!(volatile.read(), 0b0111);
+    /// ```
+    pub fn set_bits_cs(self, bits: T)
+    where
+        T: BitOr<Output = T>,
+    {
+        self.update_cs(|value| value | bits)
+    }
+
+    /// Clears the given `bits` inside a critical section.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 0b0101u8;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    /// volatile.clear_bits_cs(0b0011);
+    ///
+    /// assert_eq!(volatile.read(), 0b0100);
+    /// ```
+    pub fn clear_bits_cs(self, bits: T)
+    where
+        T: BitAnd<Output = T> + Not<Output = T>,
+    {
+        self.update_cs(|value| value & !bits)
+    }
+}
@@ -0,0 +1,184 @@
+use core::{fmt, ptr::NonNull};
+
+use super::VolatilePtr;
+use crate::access::Readable;
+
+/// A [`fmt::Debug`] adapter that reads the referenced value when formatted.
+///
+/// Created through [`VolatilePtr::debug_value`]. Each formatting performs new volatile reads, so
+/// only use this type for memory where reads have no side effects.
+///
+/// With the `defmt` feature, this type also implements `defmt::Format`, so values can be logged
+/// through macros like `defmt::info!`.
+///
+/// Slices are read and printed element by element. The number of printed elements can be
+/// limited through [`limit`][DebugValue::limit]. Use [`VolatilePtr::debug_elements`] to print
+/// arrays the same way.
+pub struct DebugValue<'a, T, A>
+where
+    T: ?Sized,
+{
+    pointer: VolatilePtr<'a, T, A>,
+    limit: Option<usize>,
+}
+
+impl<T, A> Copy for DebugValue<'_, T, A> where T: ?Sized {}
+
+impl<T, A> Clone for DebugValue<'_, T, A>
+where
+    T: ?Sized,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, A> VolatilePtr<'a, T, A>
+where
+    T: ?Sized,
+    A: Readable,
+{
+    /// Returns an adapter that performs a volatile read of the referenced value when formatted
+    /// through [`fmt::Debug`].
+    ///
+    /// The `Debug` implementation of `VolatilePtr` only prints the address since reads might
+    /// have side effects. Use this method to opt into printing the value of memory that is safe
+    /// to read.
+    ///
+    /// Slices are read element by element. Arrays are read as a whole; use
+    /// [`debug_elements`][VolatilePtr::debug_elements] to read them element by element instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = (1u8, 'a');
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut value)) };
+    /// assert_eq!(format!("{:?}", volatile.debug_value()), "(1, 'a')");
+    ///
+    /// let mut buffer = [1, 2, 3, 4];
+    /// let slice = unsafe { VolatilePtr::new(NonNull::from(&mut buffer[..])) };
+    /// assert_eq!(format!("{:?}", slice.debug_value().limit(2)), "[1, 2, ..]");
+    /// ```
+    pub fn debug_value(self) -> DebugValue<'a, T, A> {
+        DebugValue {
+            pointer: self,
+            limit: None,
+        }
+    }
+}
+
+impl<'a, T, A, const N: usize> VolatilePtr<'a, [T; N], A>
+where
+    A: Readable,
+{
+    /// Returns an adapter that performs volatile reads of the array elements when formatted
+    /// through [`fmt::Debug`].
+    ///
+    /// Unlike [`debug_value`][VolatilePtr::debug_value], which reads the whole array at once,
+    /// each element is read separately, like for slices. This way, the number of printed
+    /// elements can be limited through [`limit`][DebugValue::limit].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut buffer = [1, 2, 3, 4];
+    /// let array = unsafe { VolatilePtr::new(NonNull::from(&mut buffer)) };
+    /// assert_eq!(format!("{:?}", array.debug_elements().limit(3)), "[1, 2, 3, ..]");
+    /// ```
+    pub fn debug_elements(self) -> DebugValue<'a, [T], A> {
+        // SAFETY: the slice covers exactly the elements of the array
+        let pointer =
+            unsafe { self.map(|array| NonNull::slice_from_raw_parts(array.cast::<T>(), N)) };
+        DebugValue {
+            pointer,
+            limit: None,
+        }
+    }
+}
+
+impl<'a, T, A> DebugValue<'a, [T], A> {
+    /// Limits the number of printed elements to `limit`.
+    ///
+    /// If the slice is longer, the remaining elements are neither read nor printed and the
+    /// output ends with `..` instead.
+    pub fn limit(self, limit: usize) -> Self {
+        DebugValue {
+            limit: Some(limit),
+            ..self
+        }
+    }
+}
+
+impl<T, A> fmt::Debug for DebugValue<'_, T, A>
+where
+    T: fmt::Debug + Copy,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pointer.read().fmt(f)
+    }
+}
+
+impl<T, A> fmt::Debug for DebugValue<'_, [T], A>
+where
+    T: fmt::Debug + Copy,
+    A: Readable,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let len = self.pointer.as_raw_ptr().len();
+        let shown = self.limit.map_or(len, |limit| limit.min(len));
+        let mut list = f.debug_list();
+        for i in 0..shown {
+            // SAFETY: `i` is smaller than the slice length
+            list.entry(&unsafe { self.pointer.element_unchecked(i) }.read());
+        }
+        if shown < len {
+            list.finish_non_exhaustive()
+        } else {
+            list.finish()
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T, A> defmt::Format for DebugValue<'_, T, A>
+where
+    T: defmt::Format + Copy,
+    A: Readable,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        self.pointer.read().format(f)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T, A> defmt::Format for DebugValue<'_, [T], A>
+where
+    T: defmt::Format + Copy,
+    A: Readable,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let len = self.pointer.as_raw_ptr().len();
+        let shown = self.limit.map_or(len, |limit| limit.min(len));
+        defmt::write!(f, "[");
+        for i in 0..shown {
+            if i > 0 {
+                defmt::write!(f, ", ");
+            }
+            // SAFETY: `i` is smaller than the slice length
+            let value = unsafe { self.pointer.element_unchecked(i) }.read();
+            defmt::write!(f, "{}", value);
+        }
+        match (shown < len, shown > 0) {
+            (true, true) => defmt::write!(f, ", ..]"),
+            (true, false) => defmt::write!(f, "..]"),
+            (false, _) => defmt::write!(f, "]"),
+        }
+    }
+}
@@ -1,5 +1,13 @@
 /// Provides safe field projection for volatile pointers referencing structs.
 ///
+/// The macro works on both [`VolatilePtr`][crate::VolatilePtr] and
+/// [`VolatileRef`][crate::VolatileRef] values. A `VolatileRef` can also be projected through a
+/// borrow by writing `map_field!(&volatile.field)` or `map_field!(&mut volatile.field)`. The
+/// resulting `VolatileRef` borrows from the original one, with restricted shared access in the
+/// former case and the full access in the latter case.
+///
+/// For fields of unions, use [`map_union_field!`][crate::map_union_field!] instead.
+///
 /// ## Examples
 ///
 /// Accessing a struct field:
@@ -17,6 +25,41 @@
 /// assert_eq!(field_2.read(), 255);
 /// ```
 ///
+/// Borrowing struct fields of a `VolatileRef`:
+///
+/// ```
+/// use volatile::{VolatileRef, map_field};
+///
+/// struct Example { field_1: u32, field_2: u8, }
+/// let mut value = Example { field_1: 15, field_2: 255 };
+/// let mut volatile = VolatileRef::from_mut_ref(&mut value);
+///
+/// let mut field_1 = map_field!(&mut volatile.field_1);
+/// field_1.as_mut_ptr().write(16);
+///
+/// let field_2 = map_field!(&volatile.field_2);
+/// assert_eq!(field_2.as_ptr().read(), 255);
+/// // field_2.as_ptr().write(0); // compile-time error: shared borrow is read-only
+/// ```
+///
+/// Projecting fields of a dynamically sized struct, see the [`dst`][crate::dst] module:
+///
+/// ```
+/// use volatile::{map_field, slice_dst, VolatilePtr};
+/// use core::ptr::NonNull;
+///
+/// #[repr(C)]
+/// struct Table { header: u16, entries: [u16] }
+/// slice_dst!(Table);
+///
+/// let mut memory = [1u16, 2, 3];
+/// let table = unsafe {
+///     VolatilePtr::<Table>::from_raw_parts(NonNull::from(&mut memory).cast(), 2)
+/// };
+/// assert_eq!(map_field!(table.header).read(), 1);
+/// assert_eq!(map_field!(table.entries).as_raw_ptr().len(), 2);
+/// ```
+///
 /// Creating `VolatilePtr`s to unaligned field in packed structs is not allowed:
 /// ```compile_fail
 /// use volatile::{VolatilePtr, map_field};
@@ -30,8 +73,29 @@
 /// // Constructing a volatile reference to an unaligned field doesn't compile.
 /// let field_2 = map_field!(volatile.field_2);
 /// ```
+///
+/// Exclusive borrows of a `VolatileRef` can't overlap:
+/// ```compile_fail
+/// use volatile::{VolatileRef, map_field};
+///
+/// struct Example { field_1: u32, field_2: u8, }
+/// let mut value = Example { field_1: 15, field_2: 255 };
+/// let mut volatile = VolatileRef::from_mut_ref(&mut value);
+///
+/// let field_1 = map_field!(&mut volatile.field_1);
+/// let field_2 = map_field!(&mut volatile.field_2);
+/// drop(field_1);
+/// ```
 #[macro_export]
 macro_rules! map_field {
+    (&mut $volatile:ident.$place:ident) => {{
+        let volatile = $volatile.borrow_mut();
+        $crate::map_field!(volatile.$place)
+    }};
+    (&$volatile:ident.$place:ident) => {{
+        let volatile = $volatile.borrow();
+        $crate::map_field!(volatile.$place)
+    }};
     ($volatile:ident.$place:ident) => {{
         // Simulate creating a reference to the field. This is done to make
         // sure that the field is not potentially unaligned. The body of the
@@ -47,3 +111,188 @@ macro_rules! map_field {
         }
     }};
 }
+
+/// Splits a [`VolatileRef`][crate::VolatileRef] to a struct into `VolatileRef`s to distinct
+/// fields.
+///
+/// The macro borrows the given `VolatileRef` exclusively and returns a tuple with one
+/// `VolatileRef` per given field, in the given order. All of them keep the access type of the
+/// original `VolatileRef`. Since the fields don't overlap, the returned values can be used at the
+/// same time.
+///
+/// ## Examples
+///
+/// ```
+/// use volatile::{VolatileRef, split_fields};
+///
+/// struct Regs { ctrl: u32, data: u32, status: u32 }
+/// let mut value = Regs { ctrl: 0, data: 0, status: 1 };
+/// let mut regs = VolatileRef::from_mut_ref(&mut value);
+///
+/// let (mut ctrl, mut data, status) = split_fields!(regs => ctrl, data, status);
+/// data.as_mut_ptr().write(42);
+/// if status.as_ptr().read() == 1 {
+///     ctrl.as_mut_ptr().write(0b11);
+/// }
+/// assert_eq!((value.ctrl, value.data), (0b11, 42));
+/// ```
+///
+/// Listing a field more than once is a compile-time error:
+///
+/// ```compile_fail
+/// use volatile::{VolatileRef, split_fields};
+///
+/// struct Regs { ctrl: u32, data: u32 }
+/// let mut value = Regs { ctrl: 0, data: 0 };
+/// let mut regs = VolatileRef::from_mut_ref(&mut value);
+///
+/// let (ctrl, ctrl_again) = split_fields!(regs => ctrl, ctrl);
+/// ```
+#[macro_export]
+macro_rules! split_fields {
+    ($volatile:ident => $($place:ident),+ $(,)?) => {{
+        let pointer = $volatile.borrow_mut().into_ptr();
+
+        // Simulate creating mutable references to all fields at the same time. This is done
+        // to make sure that the fields are distinct, which is checked by the borrow checker,
+        // and that they are not potentially unaligned. The body of the if statement will
+        // never be executed, so it can never cause any UB.
+        if false {
+            let _ref_to_struct = unsafe { &mut *pointer.as_raw_ptr().as_ptr() };
+            let _ref_to_fields = ($(&mut _ref_to_struct.$place,)+);
+        }
+
+        ($(
+            unsafe {
+                $crate::VolatileRef::from_ptr(pointer.map(|ptr| {
+                    core::ptr::NonNull::new(core::ptr::addr_of_mut!((*ptr.as_ptr()).$place))
+                        .unwrap()
+                }))
+            },
+        )+)
+    }};
+}
+
+/// Provides field projection for volatile pointers to uninitialized structs.
+///
+/// Maps a `VolatilePtr<MaybeUninit<Struct>>` (or `VolatileRef`) to a pointer to the
+/// `MaybeUninit` field, without reading or creating references to the uninitialized memory.
+/// After all fields were initialized, use
+/// [`VolatilePtr::assume_init`][crate::VolatilePtr::assume_init] to get a pointer to the
+/// initialized struct.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{map_uninit_field, VolatilePtr};
+/// use core::{mem::MaybeUninit, ptr::NonNull};
+///
+/// #[derive(Clone, Copy)]
+/// struct Descriptor { addr: u64, len: u32, flags: u16 }
+///
+/// let mut memory = MaybeUninit::<Descriptor>::uninit();
+/// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut memory)) };
+///
+/// map_uninit_field!(volatile.addr).init(0x1000);
+/// map_uninit_field!(volatile.len).init(512);
+/// map_uninit_field!(volatile.flags).init(0b1);
+///
+/// // SAFETY: all fields were initialized
+/// let descriptor = unsafe { volatile.assume_init() };
+/// assert_eq!(descriptor.read().len, 512);
+/// ```
+#[macro_export]
+macro_rules! map_uninit_field {
+    ($volatile:ident.$place:ident) => {{
+        // Simulate creating a reference to the field. This is done to make
+        // sure that the field is not potentially unaligned. The body of the
+        // if statement will never be executed, so it can never cause any UB.
+        if false {
+            let _ref_to_field =
+                &(unsafe { &*$crate::__private::uninit_inner($volatile.as_raw_ptr()) }).$place;
+        }
+
+        unsafe {
+            $volatile.map(|ptr| {
+                let inner = $crate::__private::uninit_inner(ptr);
+                $crate::__private::uninit_field(core::ptr::addr_of_mut!((*inner).$place))
+            })
+        }
+    }};
+}
+
+/// Provides field projection for volatile pointers referencing unions.
+///
+/// All fields of a union overlap, so each field is a different view of the same memory. The
+/// macro returns a `VolatilePtr` (or `VolatileRef`) to the given view. Reading a view is only
+/// sound if every bit pattern is valid for it, so the macro requires the field type to
+/// implement [`AnyBitPattern`][crate::bit_pattern::AnyBitPattern]. Like
+/// [`map_field!`][crate::map_field!], it rejects potentially unaligned fields of packed unions.
+///
+/// A `VolatileRef` can also be projected through a borrow by writing
+/// `map_union_field!(&volatile.field)` or `map_union_field!(&mut volatile.field)`.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::{map_field, map_union_field, VolatilePtr};
+/// use core::ptr::NonNull;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Halves { low: u16, high: u16 }
+/// unsafe impl volatile::bit_pattern::AnyBitPattern for Halves {}
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// union Register { raw: u32, halves: Halves }
+///
+/// let mut value = Register { raw: 0 };
+/// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut value)) };
+///
+/// let halves = map_union_field!(volatile.halves);
+/// map_field!(halves.low).write(0x1234);
+/// map_field!(halves.high).write(0xabcd);
+/// assert_eq!(map_union_field!(volatile.raw).read() & 0xffff, 0x1234);
+/// ```
+///
+/// Fields with invalid bit patterns are not allowed:
+///
+/// ```compile_fail
+/// use volatile::{map_union_field, VolatilePtr};
+/// use core::ptr::NonNull;
+///
+/// #[derive(Clone, Copy)]
+/// union Register { raw: u8, flag: bool }
+///
+/// let mut value = Register { raw: 2 };
+/// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut value)) };
+/// let flag = map_union_field!(volatile.flag);
+/// ```
+#[macro_export]
+macro_rules! map_union_field {
+    (&mut $volatile:ident.$place:ident) => {{
+        let volatile = $volatile.borrow_mut();
+        $crate::map_union_field!(volatile.$place)
+    }};
+    (&$volatile:ident.$place:ident) => {{
+        let volatile = $volatile.borrow();
+        $crate::map_union_field!(volatile.$place)
+    }};
+    ($volatile:ident.$place:ident) => {{
+        // Simulate creating a reference to the field. This is done to make
+        // sure that the field is not potentially unaligned and that it is valid
+        // for any bit pattern. The body of the if statement will never be
+        // executed, so it can never cause any UB.
+        if false {
+            let _ref_to_field = unsafe { &(*$volatile.as_raw_ptr().as_ptr()).$place };
+            $crate::__private::assert_any_bit_pattern(_ref_to_field);
+        }
+
+        unsafe {
+            $volatile.map(|ptr| {
+                core::ptr::NonNull::new(core::ptr::addr_of_mut!((*ptr.as_ptr()).$place)).unwrap()
+            })
+        }
+    }};
+}
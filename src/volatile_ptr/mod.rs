@@ -2,8 +2,17 @@ use core::{cmp::Ordering, fmt, hash, marker::PhantomData, ptr::NonNull};
 
 use crate::access::ReadWrite;
 
+#[cfg(feature = "mmio-asm")]
+mod asm;
+mod bits;
+#[cfg(feature = "critical-section")]
+mod critical_section;
+mod debug_value;
 mod macros;
 mod operations;
+mod send;
+mod uninit;
+mod width;
 
 #[cfg(test)]
 mod tests;
@@ -12,6 +21,14 @@ mod unstable;
 #[cfg(feature = "very_unstable")]
 mod very_unstable;
 
+pub use debug_value::DebugValue;
+pub use send::SendVolatilePtr;
+
+#[cfg(feature = "mmio-asm")]
+use asm::{read_volatile as read_no_tear, write_volatile as write_no_tear};
+#[cfg(not(feature = "mmio-asm"))]
+use core::ptr::{read_volatile as read_no_tear, write_volatile as write_no_tear};
+
 /// Wraps a pointer to make accesses to the referenced value volatile.
 ///
 /// Allows volatile reads and writes on the referenced value. The referenced value needs to
@@ -63,6 +80,16 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T, A> defmt::Format for VolatilePtr<'_, T, A>
+where
+    T: ?Sized,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", self.pointer.as_ptr().cast::<()>())
+    }
+}
+
 impl<T, A> PartialEq for VolatilePtr<'_, T, A>
 where
     T: ?Sized,
@@ -79,7 +106,7 @@ where
     T: ?Sized,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(Ord::cmp(&self.pointer.as_ptr(), &other.pointer.as_ptr()))
+        Some(Ord::cmp(self, other))
     }
 }
 
@@ -87,6 +114,9 @@ impl<T, A> Ord for VolatilePtr<'_, T, A>
 where
     T: ?Sized,
 {
+    // Like `PartialEq` and `Hash`, the ordering includes the pointer metadata (e.g. the
+    // length of slices), so that it stays consistent with `Eq`.
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn cmp(&self, other: &Self) -> Ordering {
         Ord::cmp(&self.pointer.as_ptr(), &other.pointer.as_ptr())
     }
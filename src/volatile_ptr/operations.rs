@@ -1,10 +1,15 @@
 use core::{
     marker::PhantomData,
+    mem,
     ptr::{self, NonNull},
 };
 
 use crate::{
-    access::{Access, ReadOnly, ReadWrite, Readable, Writable, WriteOnly},
+    access::{Access, ReadOnly, ReadWrite, Readable, RestrictTo, Writable, WriteOnly},
+    bit_pattern::{AnyBitPattern, CheckedBitPattern},
+    convert::InvalidValue,
+    dst::SliceDst,
+    width::NoTear,
     VolatilePtr,
 };
 
@@ -24,7 +29,7 @@ where
     /// - The given pointer must be valid.
     /// - No other thread must have access to the given pointer. This must remain true
     ///   for the whole lifetime of the `VolatilePtr`.
-    pub unsafe fn new(pointer: NonNull<T>) -> VolatilePtr<'a, T, ReadWrite> {
+    pub const unsafe fn new(pointer: NonNull<T>) -> VolatilePtr<'a, T, ReadWrite> {
         unsafe { VolatilePtr::new_restricted(ReadWrite, pointer) }
     }
 
@@ -50,6 +55,63 @@ where
         unsafe { Self::new_generic(pointer) }
     }
 
+    /// Creates a `VolatilePtr` for the `T` value at the given memory address.
+    ///
+    /// This is a `const` function, so it can be used to initialize `static` or `const` items
+    /// for memory-mapped devices at fixed addresses.
+    ///
+    /// ## Safety
+    ///
+    /// The address must be properly aligned for `T` and the requirements for [`Self::new`] must
+    /// be fulfilled for the resulting pointer.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `addr` is zero.
+    pub const unsafe fn from_addr(addr: usize) -> VolatilePtr<'a, T, ReadWrite>
+    where
+        T: Sized,
+    {
+        unsafe { Self::from_addr_restricted(ReadWrite, addr) }
+    }
+
+    /// Creates a `VolatilePtr` with restricted access for the `T` value at the given memory
+    /// address.
+    ///
+    /// ## Safety
+    ///
+    /// The requirements for [`Self::from_addr`] apply to this function too.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `addr` is zero.
+    pub const unsafe fn from_addr_restricted<A>(access: A, addr: usize) -> VolatilePtr<'a, T, A>
+    where
+        T: Sized,
+        A: Access,
+    {
+        assert!(addr != 0, "address must not be zero");
+        unsafe { Self::new_restricted(access, NonNull::new_unchecked(addr as *mut T)) }
+    }
+
+    /// Creates a `VolatilePtr` to a dynamically sized struct from the address and the length of
+    /// its trailing slice.
+    ///
+    /// For slices, `len` is the number of elements. For structs with a trailing slice, it is
+    /// the number of elements of that slice. See the [`dst`][crate::dst] module for details.
+    ///
+    /// ## Safety
+    ///
+    /// The requirements for [`Self::new`] apply to the resulting pointer. In particular, the
+    /// memory must be properly aligned and large enough for the header fields and `len`
+    /// elements.
+    pub unsafe fn from_raw_parts(data: NonNull<u8>, len: usize) -> VolatilePtr<'a, T, ReadWrite>
+    where
+        T: SliceDst,
+    {
+        unsafe { VolatilePtr::new(T::ptr_from_raw_parts(data, len)) }
+    }
+
     pub(super) const unsafe fn new_generic<A>(pointer: NonNull<T>) -> VolatilePtr<'a, T, A> {
         VolatilePtr {
             pointer,
@@ -70,6 +132,13 @@ where
     /// guarantees. To also get atomicity, consider looking at the `Atomic` wrapper types of
     /// the standard/`core` library.
     ///
+    /// The read is not guaranteed to be performed by a single instruction. Use
+    /// [`read_single`][Self::read_single] for registers that must not be torn.
+    ///
+    /// Reading a type with invalid bit patterns, e.g. `bool`, is undefined behavior if the
+    /// memory holds such a pattern. Use [`read_raw`][Self::read_raw] or
+    /// [`read_checked`][Self::read_checked] for memory that might hold arbitrary bits.
+    ///
     /// ## Examples
     ///
     /// ```rust
@@ -96,6 +165,9 @@ where
     /// themselves do not have atomic ordering guarantees. To also get atomicity, consider
     /// looking at the `Atomic` wrapper types of the standard/`core` library.
     ///
+    /// The write is not guaranteed to be performed by a single instruction. Use
+    /// [`write_single`][Self::write_single] for registers that must not be torn.
+    ///
     /// ## Example
     ///
     /// ```rust
@@ -116,6 +188,164 @@ where
         unsafe { ptr::write_volatile(self.pointer.as_ptr(), value) };
     }
 
+    /// Performs a volatile read of the contained value using a single load instruction.
+    ///
+    /// With the `mmio-asm` feature enabled, this method uses inline assembly that emits exactly
+    /// one plain load instruction on `x86_64` and `aarch64`. Otherwise, it behaves like
+    /// [`read`][Self::read].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let value = 42u32;
+    /// let volatile = unsafe { VolatilePtr::new_read_only(NonNull::from(&value)) };
+    /// assert_eq!(volatile.read_single(), 42);
+    /// ```
+    pub fn read_single(self) -> T
+    where
+        T: NoTear,
+        A: Readable,
+    {
+        unsafe { super::read_no_tear(self.pointer.as_ptr()) }
+    }
+
+    /// Performs a volatile write of the given `value` using a single store instruction.
+    ///
+    /// With the `mmio-asm` feature enabled, this method uses inline assembly that emits exactly
+    /// one plain store instruction on `x86_64` and `aarch64`. Otherwise, it behaves like
+    /// [`write`][Self::write].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 0u16;
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut value)) };
+    /// volatile.write_single(0x1234);
+    /// assert_eq!(volatile.read_single(), 0x1234);
+    /// ```
+    pub fn write_single(self, value: T)
+    where
+        T: NoTear,
+        A: Writable,
+    {
+        unsafe { super::write_no_tear(self.pointer.as_ptr(), value) };
+    }
+
+    /// Performs a volatile read and converts the value to `E`.
+    ///
+    /// Returns the raw value as [`InvalidValue`] error if the conversion fails. This is useful
+    /// for registers that hold enum values, see [`volatile_enum!`][crate::volatile_enum!].
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::{num::NonZeroU8, ptr::NonNull};
+    ///
+    /// let mut value = 0u8;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    ///
+    /// assert!(volatile.read_as::<NonZeroU8>().is_err());
+    /// volatile.write(3);
+    /// assert_eq!(volatile.read_as::<NonZeroU8>().unwrap().get(), 3);
+    /// ```
+    pub fn read_as<E>(self) -> Result<E, InvalidValue<T>>
+    where
+        T: Copy,
+        A: Readable,
+        E: TryFrom<T>,
+    {
+        let value = self.read();
+        E::try_from(value).map_err(|_| InvalidValue(value))
+    }
+
+    /// Converts the given value to `T` and performs a volatile write.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 0u32;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    ///
+    /// volatile.write_from(true);
+    /// assert_eq!(volatile.read(), 1);
+    /// ```
+    pub fn write_from<E>(self, value: E)
+    where
+        T: Copy,
+        A: Writable,
+        E: Into<T>,
+    {
+        self.write(value.into());
+    }
+
+    /// Performs a volatile read of a type that is valid for any bit pattern.
+    ///
+    /// This method behaves like [`read`][Self::read], but the [`AnyBitPattern`] bound ensures
+    /// that the read is sound no matter what the referenced memory contains. Prefer this
+    /// method for reading device memory that might hold arbitrary bits.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = [1u16, 2];
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    /// assert_eq!(volatile.read_raw(), [1, 2]);
+    /// ```
+    pub fn read_raw(self) -> T
+    where
+        T: AnyBitPattern,
+        A: Readable,
+    {
+        self.read()
+    }
+
+    /// Performs a volatile read and returns the value if its bit pattern is valid.
+    ///
+    /// The value is read as [`CheckedBitPattern::Bits`], so that invalid bit patterns are
+    /// never interpreted as `T`. Returns `None` if the bits don't form a valid `T`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 1u8;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    /// // SAFETY: `bool` has the same size and alignment as `u8`
+    /// let flag = unsafe { volatile.map(|ptr| ptr.cast::<bool>()) };
+    /// assert_eq!(flag.read_checked(), Some(true));
+    ///
+    /// volatile.write(2);
+    /// assert_eq!(flag.read_checked(), None);
+    /// ```
+    pub fn read_checked(self) -> Option<T>
+    where
+        T: CheckedBitPattern,
+        A: Readable,
+    {
+        let bits = unsafe { ptr::read_volatile(self.pointer.as_ptr().cast::<T::Bits>()) };
+        if T::is_valid_bit_pattern(&bits) {
+            // SAFETY: the bits are a valid `T` and `T::Bits` has the same size as `T`
+            Some(unsafe { mem::transmute_copy(&bits) })
+        } else {
+            None
+        }
+    }
+
     /// Updates the contained value using the given closure and volatile instructions.
     ///
     /// Performs a volatile read of the contained value, passes it to the
@@ -142,6 +372,164 @@ where
         self.write(new);
     }
 
+    /// Writes the given value and returns the previous value.
+    ///
+    /// Performs a volatile read followed by a volatile write. Note that the two operations are
+    /// not atomic.
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 42;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    ///
+    /// assert_eq!(volatile.replace(50), 42);
+    /// assert_eq!(volatile.read(), 50);
+    /// ```
+    pub fn replace(self, value: T) -> T
+    where
+        T: Copy,
+        A: Readable + Writable,
+    {
+        let old = self.read();
+        self.write(value);
+        old
+    }
+
+    /// Swaps the referenced values of `self` and `other`.
+    ///
+    /// Both values are read before any of them is written.
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut values = (1, 2);
+    /// let a = unsafe { VolatilePtr::new((&mut values.0).into()) };
+    /// let b = unsafe { VolatilePtr::new((&mut values.1).into()) };
+    ///
+    /// a.swap(b);
+    /// assert_eq!((a.read(), b.read()), (2, 1));
+    /// ```
+    pub fn swap<B>(self, other: VolatilePtr<'_, T, B>)
+    where
+        T: Copy,
+        A: Readable + Writable,
+        B: Readable + Writable,
+    {
+        let value = self.read();
+        let other_value = other.read();
+        self.write(other_value);
+        other.write(value);
+    }
+
+    /// Updates the contained value using the given closure and returns the new value.
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 42;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    ///
+    /// assert_eq!(volatile.update_and_get(|val| val + 1), 43);
+    /// ```
+    pub fn update_and_get<F>(self, f: F) -> T
+    where
+        T: Copy,
+        A: Readable + Writable,
+        F: FnOnce(T) -> T,
+    {
+        let new = f(self.read());
+        self.write(new);
+        new
+    }
+
+    /// Updates the contained value using the given closure and returns the previous value.
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 42;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    ///
+    /// assert_eq!(volatile.get_and_update(|val| val + 1), 42);
+    /// assert_eq!(volatile.read(), 43);
+    /// ```
+    pub fn get_and_update<F>(self, f: F) -> T
+    where
+        T: Copy,
+        A: Readable + Writable,
+        F: FnOnce(T) -> T,
+    {
+        let old = self.read();
+        self.write(f(old));
+        old
+    }
+
+    /// Updates the contained value using the given closure, which can return additional data.
+    ///
+    /// The closure returns a tuple of the new value and a result, which is passed through to
+    /// the caller.
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut status = 0b1010u8;
+    /// let volatile = unsafe { VolatilePtr::new((&mut status).into()) };
+    ///
+    /// // clear the lowest set bit and return its index
+    /// let index = volatile.modify(|val| (val & (val - 1), val.trailing_zeros()));
+    /// assert_eq!(index, 1);
+    /// assert_eq!(volatile.read(), 0b1000);
+    /// ```
+    pub fn modify<F, R>(self, f: F) -> R
+    where
+        T: Copy,
+        A: Readable + Writable,
+        F: FnOnce(T) -> (T, R),
+    {
+        let (new, result) = f(self.read());
+        self.write(new);
+        result
+    }
+
+    /// Updates the contained value using the given closure, unless the closure returns `None`.
+    ///
+    /// Returns `Ok(previous_value)` if the closure returned `Some` and the new value was
+    /// written, and `Err(previous_value)` otherwise. No write is performed in the latter case,
+    /// which is useful for registers whose writes have side effects.
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut value = 9u8;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    ///
+    /// assert_eq!(volatile.fetch_update(|val| val.checked_add(1)), Ok(9));
+    /// assert_eq!(volatile.fetch_update(|val| (val < 10).then_some(0)), Err(10));
+    /// assert_eq!(volatile.read(), 10);
+    /// ```
+    pub fn fetch_update<F>(self, f: F) -> Result<T, T>
+    where
+        T: Copy,
+        A: Readable + Writable,
+        F: FnOnce(T) -> Option<T>,
+    {
+        let old = self.read();
+        match f(old) {
+            Some(new) => {
+                self.write(new);
+                Ok(old)
+            }
+            None => Err(old),
+        }
+    }
+
     /// Extracts the wrapped raw pointer.
     ///
     /// ## Example
@@ -261,3 +649,122 @@ where
         unsafe { VolatilePtr::new_restricted(WriteOnly, self.pointer) }
     }
 }
+
+/// Methods for converting between access types.
+impl<'a, T, A> VolatilePtr<'a, T, A>
+where
+    T: ?Sized,
+    A: Access,
+{
+    /// Restricts access permissions to the given access type `B`.
+    ///
+    /// Unlike [`read_only`][VolatilePtr::read_only] and
+    /// [`write_only`][VolatilePtr::write_only], this method is available for all access
+    /// types, so it can be used in code that is generic over the access type. See
+    /// [`RestrictTo`] for the possible conversions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::{access::{NoAccess, ReadOnly, RestrictTo}, VolatilePtr};
+    /// use core::ptr::NonNull;
+    ///
+    /// fn hide<A: RestrictTo<NoAccess>>(ptr: VolatilePtr<u32, A>) -> VolatilePtr<u32, NoAccess> {
+    ///     ptr.restrict()
+    /// }
+    ///
+    /// let mut value = 5u32;
+    /// let volatile = unsafe { VolatilePtr::new((&mut value).into()) };
+    /// let read_only = volatile.restrict::<ReadOnly>();
+    /// assert_eq!(read_only.read(), 5);
+    /// let hidden = hide(read_only);
+    /// // hidden.read(); // compile-time error
+    /// ```
+    pub fn restrict<B>(self) -> VolatilePtr<'a, T, B>
+    where
+        A: RestrictTo<B>,
+        B: Access,
+    {
+        unsafe { VolatilePtr::new_restricted(B::default(), self.pointer) }
+    }
+
+    /// Changes the access permissions to the given access type `B`, which might allow more
+    /// operations than the current one.
+    ///
+    /// This is useful for registers whose permissions change at runtime, e.g. when a
+    /// firmware unlocks a previously read-only register.
+    ///
+    /// ## Safety
+    ///
+    /// The referenced memory must support all operations allowed by `B`. If the memory was
+    /// previously shared as read-only, no other pointer must be used to read it while writes
+    /// through the returned pointer are possible.
+    pub unsafe fn upgrade_access<B>(self) -> VolatilePtr<'a, T, B>
+    where
+        B: Access,
+    {
+        unsafe { VolatilePtr::new_restricted(B::default(), self.pointer) }
+    }
+}
+
+/// Crate-internal helpers for volatile slices that don't require the `unstable` feature.
+impl<'a, T, A> VolatilePtr<'a, [T], A> {
+    /// Returns a pointer to the slice element at the given index, without bounds checking.
+    ///
+    /// ## Safety
+    ///
+    /// The index must be smaller than the length of the slice.
+    pub(crate) unsafe fn element_unchecked(self, index: usize) -> VolatilePtr<'a, T, A>
+    where
+        A: Access,
+    {
+        debug_assert!(index < self.pointer.len());
+        unsafe { self.map(|slice| NonNull::new_unchecked(slice.as_ptr().cast::<T>().add(index))) }
+    }
+}
+
+/// Methods for converting slices to arrays.
+impl<'a, T, A> VolatilePtr<'a, [T], A> {
+    /// Converts the slice pointer to an array pointer if the slice has exactly `N` elements.
+    ///
+    /// Returns `None` if the length of the slice is not `N`. The same conversion is also
+    /// available through the [`TryFrom`] trait.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let values = [1, 2, 3, 4];
+    /// let volatile = unsafe { VolatilePtr::new_read_only(NonNull::from(&values[1..])) };
+    /// assert!(volatile.as_array::<4>().is_none());
+    /// let array = volatile.as_array::<3>().unwrap();
+    /// assert_eq!(array.read(), [2, 3, 4]);
+    /// ```
+    pub fn as_array<const N: usize>(self) -> Option<VolatilePtr<'a, [T; N], A>>
+    where
+        A: Access,
+    {
+        if self.pointer.len() == N {
+            // SAFETY: the slice has exactly `N` elements, so it has the same layout as `[T; N]`
+            Some(unsafe { self.map(|slice| slice.cast()) })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, A, const N: usize> TryFrom<VolatilePtr<'a, [T], A>> for VolatilePtr<'a, [T; N], A>
+where
+    A: Access,
+{
+    type Error = VolatilePtr<'a, [T], A>;
+
+    /// Converts the slice pointer to an array pointer if the slice has exactly `N` elements.
+    ///
+    /// Gives back the slice pointer if its length is not `N`.
+    fn try_from(slice: VolatilePtr<'a, [T], A>) -> Result<Self, Self::Error> {
+        slice.as_array().ok_or(slice)
+    }
+}
@@ -0,0 +1,77 @@
+use core::fmt;
+
+use super::VolatilePtr;
+use crate::access::ReadWrite;
+
+/// A [`VolatilePtr`] that can be sent to another thread.
+///
+/// Created through the unsafe [`VolatilePtr::assume_send`] method. Unlike `VolatilePtr`, this
+/// type is not [`Copy`], so it represents a single handle that is moved to the other thread.
+/// Use [`into_ptr`][Self::into_ptr] to turn it back into a `VolatilePtr` there.
+///
+/// ## Example
+///
+/// ```
+/// use volatile::VolatilePtr;
+/// use core::ptr::NonNull;
+///
+/// let mut value = 0u32;
+/// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut value)) };
+///
+/// // SAFETY: the pointer is only used by the worker thread
+/// let handle = unsafe { volatile.assume_send() };
+/// std::thread::scope(|s| {
+///     s.spawn(move || handle.into_ptr().write(42));
+/// });
+/// assert_eq!(value, 42);
+/// ```
+#[repr(transparent)]
+pub struct SendVolatilePtr<'a, T, A = ReadWrite>
+where
+    T: ?Sized,
+{
+    pointer: VolatilePtr<'a, T, A>,
+}
+
+impl<'a, T, A> VolatilePtr<'a, T, A>
+where
+    T: ?Sized,
+{
+    /// Wraps this `VolatilePtr` in a [`SendVolatilePtr`] that implements [`Send`].
+    ///
+    /// The lifetime and access type are preserved, so this is preferable to casting the pointer
+    /// to an address and back.
+    ///
+    /// ## Safety
+    ///
+    /// While the pointer is used on the receiving thread, no other thread must access the
+    /// referenced memory through any copy of this `VolatilePtr` or through other pointers.
+    pub unsafe fn assume_send(self) -> SendVolatilePtr<'a, T, A> {
+        SendVolatilePtr { pointer: self }
+    }
+}
+
+impl<'a, T, A> SendVolatilePtr<'a, T, A>
+where
+    T: ?Sized,
+{
+    /// Turns this value back into a [`VolatilePtr`].
+    pub fn into_ptr(self) -> VolatilePtr<'a, T, A> {
+        self.pointer
+    }
+}
+
+// SAFETY: the safety requirements of `assume_send` guarantee that the referenced memory is not
+// accessed concurrently.
+unsafe impl<T, A> Send for SendVolatilePtr<'_, T, A> where T: Send + ?Sized {}
+
+impl<T, A> fmt::Debug for SendVolatilePtr<'_, T, A>
+where
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SendVolatilePtr")
+            .field(&self.pointer.pointer.as_ptr())
+            .finish()
+    }
+}
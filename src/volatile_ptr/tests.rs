@@ -1,6 +1,7 @@
 use crate::{
     access::{ReadOnly, ReadWrite, WriteOnly},
-    map_field, VolatilePtr,
+    map_field, map_uninit_field, map_union_field, slice_dst, split_fields, VolatilePtr,
+    VolatileRef,
 };
 use core::ptr::NonNull;
 
@@ -177,3 +178,351 @@ fn test_chunks() {
     assert_eq!(chunks.index(0).read(), [1, 2, 3]);
     assert_eq!(chunks.index(1).read(), [10, 11, 12]);
 }
+
+#[test]
+fn test_copy_as() {
+    let mut words = [0u32; 2];
+    let bytes = unsafe { core::slice::from_raw_parts_mut(words.as_mut_ptr().cast::<u8>(), 8) };
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut *bytes)) };
+    volatile.copy_from_slice_as::<u16>(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let mut dst = [0; 8];
+    volatile.copy_into_slice_as::<u32>(&mut dst);
+    assert_eq!(dst, [1, 2, 3, 4, 5, 6, 7, 8]);
+    let tail = unsafe { VolatilePtr::new(NonNull::from(&mut bytes[4..])) };
+    tail.fill_as::<u32>(0);
+    assert_eq!(words, [u32::from_ne_bytes([1, 2, 3, 4]), 0]);
+}
+
+#[test]
+#[should_panic]
+fn test_copy_as_unaligned() {
+    let mut words = [0u32; 2];
+    let bytes = unsafe { core::slice::from_raw_parts_mut(words.as_mut_ptr().cast::<u8>(), 8) };
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut bytes[1..5])) };
+    volatile.fill_as::<u32>(0);
+}
+
+#[test]
+fn test_single_access() {
+    #[derive(Debug, PartialEq)]
+    struct S {
+        a: u8,
+        b: i16,
+        c: u32,
+        d: usize,
+    }
+
+    let mut val = S {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+    };
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    let a = map_field!(volatile.a);
+    a.write_single(a.read_single() + 10);
+    let b = map_field!(volatile.b);
+    b.write_single(b.read_single() + 10);
+    let c = map_field!(volatile.c);
+    c.write_single(c.read_single() + 10);
+    let d = map_field!(volatile.d);
+    d.write_single(d.read_single() + 10);
+    assert_eq!(
+        val,
+        S {
+            a: 11,
+            b: 12,
+            c: 13,
+            d: 14
+        }
+    );
+}
+
+#[test]
+fn test_padded_write() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct S {
+        a: u32,
+        b: bool,
+    }
+
+    // padded types must not be transmuted to integers, even with the `mmio-asm` feature
+    let mut val = S { a: 1, b: false };
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    volatile.write(S { a: 2, b: true });
+    assert_eq!(volatile.read(), S { a: 2, b: true });
+
+    let mut tuple = (1u8, 2u16);
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut tuple)) };
+    volatile.write((3, 4));
+    assert_eq!(volatile.read(), (3, 4));
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn test_scroll_rows() {
+    let mut screen = [[1u8; 3], [2; 3], [3; 3], [4; 3]];
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut screen)) };
+    volatile.scroll_rows(2, 0);
+    assert_eq!(screen, [[3; 3], [4; 3], [0; 3], [0; 3]]);
+    volatile.scroll_rows(5, 9);
+    assert_eq!(screen, [[9; 3]; 4]);
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn test_array() {
+    let mut val = [1u32, 2, 3];
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    volatile.index(0).update(|v| v + 10);
+    assert_eq!(volatile.read_array(), [11, 2, 3]);
+    volatile.write_array([4, 5, 6]);
+    assert_eq!(volatile.iter().map(|v| v.read()).sum::<u32>(), 15);
+
+    let slice = volatile.as_slice();
+    assert!(VolatilePtr::<[u32; 2]>::try_from(slice).is_err());
+    let array: VolatilePtr<[u32; 2]> = slice.index(1..).try_into().unwrap();
+    array.fill(7);
+    assert_eq!(val, [4, 7, 7]);
+}
+
+#[test]
+fn test_ref_field_projection() {
+    #[derive(Debug, PartialEq)]
+    struct S {
+        field_1: u32,
+        field_2: bool,
+    }
+
+    let mut val = S {
+        field_1: 60,
+        field_2: true,
+    };
+    let mut volatile = VolatileRef::from_mut_ref(&mut val);
+    {
+        let mut field_1 = map_field!(&mut volatile.field_1);
+        field_1.as_mut_ptr().update(|v| v + 1);
+    }
+    {
+        let field_2 = map_field!(&volatile.field_2);
+        let field_2_copy = field_2;
+        assert!(field_2_copy.as_ptr().read());
+        assert!(field_2.as_ptr().read());
+    }
+    map_field!(volatile.field_2).as_mut_ptr().write(false);
+    assert_eq!(
+        val,
+        S {
+            field_1: 61,
+            field_2: false
+        }
+    );
+}
+
+#[test]
+fn test_split_fields() {
+    struct S {
+        field_1: u32,
+        field_2: bool,
+        field_3: u8,
+    }
+
+    let mut val = S {
+        field_1: 60,
+        field_2: true,
+        field_3: 1,
+    };
+    let mut volatile = VolatileRef::from_mut_ref(&mut val).read_only();
+    let (field_3, field_1) = split_fields!(volatile => field_3, field_1);
+    assert_eq!(field_3.as_ptr().read(), 1);
+    assert_eq!(field_1.as_ptr().read(), 60);
+    let field_2 = map_field!(&volatile.field_2);
+    assert!(field_2.as_ptr().read());
+}
+
+#[test]
+fn test_restrict() {
+    use crate::access::{Access, NoAccess, ReadOnly, RestrictTo, WriteOnly};
+
+    fn restrict_generic<A, B>(volatile: VolatilePtr<u32, A>) -> VolatilePtr<u32, B>
+    where
+        A: RestrictTo<B>,
+        B: Access,
+    {
+        volatile.restrict()
+    }
+
+    let mut val = 1u32;
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    let read_only: VolatilePtr<_, ReadOnly> = restrict_generic(volatile);
+    assert_eq!(read_only.read(), 1);
+    let _: VolatilePtr<_, NoAccess> = restrict_generic(read_only);
+    let write_only: VolatilePtr<_, WriteOnly> = volatile.restrict();
+    write_only.write(2);
+    let upgraded = unsafe { read_only.upgrade_access::<crate::access::ReadWrite>() };
+    upgraded.update(|v| v + 1);
+    assert_eq!(val, 3);
+
+    let volatile = VolatileRef::from_mut_ref(&mut val).restrict::<ReadOnly>();
+    assert_eq!(volatile.as_ptr().read(), 3);
+}
+
+#[test]
+fn test_debug_value() {
+    extern crate std;
+    use std::format;
+
+    let mut val = [[1u8, 2], [3, 4]];
+    let volatile = VolatileRef::from_mut_ref(&mut val);
+    assert_eq!(format!("{:?}", volatile.debug_value()), "[[1, 2], [3, 4]]");
+    let elements = volatile.debug_elements();
+    assert_eq!(format!("{:?}", elements.limit(1)), "[[1, 2], ..]");
+    assert_eq!(format!("{:?}", elements.limit(5)), "[[1, 2], [3, 4]]");
+    assert_eq!(format!("{:?}", elements.limit(0)), "[..]");
+}
+
+#[test]
+fn test_update_family() {
+    let mut val = 1u32;
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    assert_eq!(volatile.replace(2), 1);
+    assert_eq!(volatile.update_and_get(|v| v * 3), 6);
+    assert_eq!(volatile.get_and_update(|v| v + 1), 6);
+    assert!(volatile.modify(|v| (v - 7, v == 7)));
+    assert_eq!(volatile.fetch_update(|_| None), Err(0));
+    assert_eq!(volatile.fetch_update(|v| Some(v + 5)), Ok(0));
+    assert_eq!(val, 5);
+}
+
+#[test]
+fn test_bits() {
+    let mut val = -1i16;
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    assert!(volatile.test_bit(15));
+    volatile.clear_bits(i16::MIN);
+    assert!(!volatile.test_bit(15));
+    assert!(volatile.read_only().test_all(0x7fff));
+    volatile.write_only().write(0);
+    volatile.write_bits(0x00f0, 0x0a5a);
+    assert_eq!(val, 0x0050);
+}
+
+#[test]
+fn test_read_checked() {
+    use core::num::NonZeroU32;
+
+    let mut val = 0u32;
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    let non_zero = unsafe { volatile.map(|ptr| ptr.cast::<NonZeroU32>()) };
+    let character = unsafe { volatile.map(|ptr| ptr.cast::<char>()) };
+    assert_eq!(non_zero.read_checked(), None);
+    assert_eq!(character.read_checked(), Some('\0'));
+    volatile.write(0xd800);
+    assert_eq!(non_zero.read_checked(), NonZeroU32::new(0xd800));
+    assert_eq!(character.read_checked(), None);
+    assert_eq!(volatile.read_raw(), 0xd800);
+}
+
+#[test]
+fn test_uninit() {
+    use core::mem::MaybeUninit;
+
+    #[derive(Clone, Copy)]
+    struct S {
+        field_1: u32,
+        field_2: bool,
+    }
+
+    let mut val = MaybeUninit::<S>::uninit();
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    map_uninit_field!(volatile.field_1).init(7);
+    map_uninit_field!(volatile.field_2).init(true);
+    let volatile = unsafe { volatile.assume_init() };
+    assert_eq!(volatile.read().field_1, 7);
+    assert!(map_field!(volatile.field_2).read());
+
+    let mut val = [MaybeUninit::<u8>::uninit(); 2];
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val[..])) };
+    let volatile = volatile.init_from_slice(&[1, 2]);
+    assert_eq!(unsafe { volatile.element_unchecked(1) }.read(), 2);
+}
+
+#[test]
+fn test_slice_dst() {
+    #[repr(C)]
+    struct Table {
+        len: u32,
+        flags: u32,
+        entries: [u64],
+    }
+    slice_dst!(Table);
+
+    let mut memory = [0u64; 4];
+    let data = NonNull::from(&mut memory).cast();
+    let table = unsafe { VolatilePtr::<Table>::from_raw_parts(data, 3) };
+    map_field!(table.len).write(3);
+    map_field!(table.flags).write(1);
+    let entries = map_field!(table.entries);
+    assert_eq!(entries.as_raw_ptr().len(), 3);
+    unsafe { entries.element_unchecked(2) }.write(42);
+    assert_eq!(map_field!(table.len).read(), 3);
+    assert_eq!(memory[1..], [0, 0, 42]);
+}
+
+#[test]
+fn test_union_field() {
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    union U {
+        word: u32,
+        bytes: [u8; 4],
+    }
+
+    let mut val = U { word: 0 };
+    let volatile = VolatileRef::from_mut_ref(&mut val).read_only();
+    let bytes = map_union_field!(volatile.bytes);
+    assert_eq!(bytes.as_ptr().read(), [0; 4]);
+
+    let mut val = U { word: 0 };
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    map_union_field!(volatile.bytes).write([0xff; 4]);
+    assert_eq!(map_union_field!(volatile.word).read(), u32::MAX);
+
+    let mut volatile = VolatileRef::from_mut_ref(&mut val);
+    map_union_field!(&mut volatile.word).as_mut_ptr().write(1);
+    let bytes = map_union_field!(&volatile.bytes);
+    assert_eq!(bytes.as_ptr().read(), 1u32.to_ne_bytes());
+}
+
+#[test]
+fn test_volatile_access() {
+    use crate::{
+        access::{Readable, Writable},
+        VolatileAccess,
+    };
+
+    fn toggle_second<'a, V>(volatile: V) -> bool
+    where
+        V: VolatileAccess<'a, Target = [bool; 2], Access = ReadWrite>,
+    {
+        let mut second = unsafe { volatile.map(|ptr| ptr.cast::<bool>().add(1)) };
+        second.update(|value| !value);
+        second.restrict::<ReadOnly>().read()
+    }
+
+    fn read_generic<'a, V>(volatile: &V) -> V::Target
+    where
+        V: VolatileAccess<'a>,
+        V::Target: Copy,
+        V::Access: Readable + Writable,
+    {
+        volatile.read()
+    }
+
+    let mut val = [false, false];
+    let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut val)) };
+    assert!(toggle_second(volatile));
+    assert_eq!(read_generic(&volatile), [false, true]);
+    assert!(!toggle_second(VolatileRef::from_mut_ref(&mut val)));
+    assert_eq!(val, [false, false]);
+}
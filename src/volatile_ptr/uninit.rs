@@ -0,0 +1,105 @@
+use core::{mem::MaybeUninit, ptr::NonNull};
+
+use super::VolatilePtr;
+use crate::access::{Access, Writable};
+
+/// Methods for initializing volatile memory.
+///
+/// None of these methods read the uninitialized value. Fields of `MaybeUninit` structs can be
+/// projected through the [`map_uninit_field!`][crate::map_uninit_field!] macro.
+impl<'a, T, A> VolatilePtr<'a, MaybeUninit<T>, A>
+where
+    A: Access,
+{
+    /// Initializes the referenced value through a volatile write of `value`.
+    ///
+    /// Returns a pointer to the now initialized value.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::{mem::MaybeUninit, ptr::NonNull};
+    ///
+    /// let mut memory = MaybeUninit::<u32>::uninit();
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut memory)) };
+    ///
+    /// let initialized = volatile.init(42);
+    /// assert_eq!(initialized.read(), 42);
+    /// ```
+    pub fn init(self, value: T) -> VolatilePtr<'a, T, A>
+    where
+        T: Copy,
+        A: Writable,
+    {
+        let pointer = unsafe { self.assume_init() };
+        pointer.write(value);
+        pointer
+    }
+
+    /// Converts this pointer into a pointer to an initialized `T`.
+    ///
+    /// ## Safety
+    ///
+    /// The referenced value must be fully initialized, e.g. through
+    /// [`init`][Self::init] or by initializing all fields through
+    /// [`map_uninit_field!`][crate::map_uninit_field!].
+    pub unsafe fn assume_init(self) -> VolatilePtr<'a, T, A> {
+        unsafe { self.map(NonNull::cast) }
+    }
+}
+
+/// Methods for initializing volatile slices.
+impl<'a, T, A> VolatilePtr<'a, [MaybeUninit<T>], A>
+where
+    A: Access,
+{
+    /// Initializes the referenced slice by copying the elements of `src` through volatile
+    /// writes.
+    ///
+    /// Returns a pointer to the now initialized slice.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of `src` differs from the length of this slice.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::{mem::MaybeUninit, ptr::NonNull};
+    ///
+    /// let mut memory = [MaybeUninit::<u16>::uninit(); 3];
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut memory[..])) };
+    ///
+    /// let initialized = volatile.init_from_slice(&[1, 2, 3]);
+    /// # #[cfg(feature = "unstable")]
+    /// assert_eq!(initialized.index(2).read(), 3);
+    /// ```
+    pub fn init_from_slice(self, src: &[T]) -> VolatilePtr<'a, [T], A>
+    where
+        T: Copy,
+        A: Writable,
+    {
+        let pointer = unsafe { self.assume_init() };
+        assert_eq!(
+            pointer.as_raw_ptr().len(),
+            src.len(),
+            "source slice length does not match destination slice length"
+        );
+        for (i, value) in src.iter().enumerate() {
+            // SAFETY: we checked that both slices have the same length
+            unsafe { pointer.element_unchecked(i) }.write(*value);
+        }
+        pointer
+    }
+
+    /// Converts this pointer into a pointer to a slice of initialized `T` values.
+    ///
+    /// ## Safety
+    ///
+    /// All elements of the referenced slice must be fully initialized.
+    pub unsafe fn assume_init(self) -> VolatilePtr<'a, [T], A> {
+        unsafe { self.map(|slice| NonNull::slice_from_raw_parts(slice.cast::<T>(), slice.len())) }
+    }
+}
@@ -1,5 +1,6 @@
 use core::{
     intrinsics,
+    mem::MaybeUninit,
     ops::{Range, RangeBounds},
     ptr::{self, NonNull},
     slice::{range, SliceIndex},
@@ -401,6 +402,336 @@ impl<'a, T, A, const N: usize> VolatilePtr<'a, [T; N], A> {
     }
 }
 
+/// Methods for volatile arrays
+///
+/// The lengths of the arrays are known at compile time, so the copy methods don't need any
+/// runtime length checks.
+///
+/// These methods are only available with the `unstable` feature enabled (requires a nightly
+/// Rust compiler).
+impl<'a, T, A, const N: usize> VolatilePtr<'a, [T; N], A> {
+    /// Applies the index operation on the wrapped array.
+    ///
+    /// This is a shorthand for `self.as_slice().index(index)`. See
+    /// [`VolatilePtr::index`] for details.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let array = [1, 2, 3];
+    /// let volatile = unsafe { VolatilePtr::new_read_only(NonNull::from(&array)) };
+    /// assert_eq!(volatile.index(1).read(), 2);
+    /// assert_eq!(volatile.index(1..).len(), 2);
+    /// ```
+    pub fn index<I>(self, index: I) -> VolatilePtr<'a, <I as SliceIndex<[T]>>::Output, A>
+    where
+        I: SliceIndex<[T]> + SliceIndex<[()]> + Clone,
+        A: Access,
+    {
+        self.as_slice().index(index)
+    }
+
+    /// Returns an iterator over the array elements.
+    pub fn iter(self) -> impl Iterator<Item = VolatilePtr<'a, T, A>>
+    where
+        A: Access,
+    {
+        self.as_slice().iter()
+    }
+
+    /// Reads the array using a volatile memcpy.
+    ///
+    /// In contrast to [`read`][VolatilePtr::read], this method doesn't perform a single
+    /// volatile read of the full array size, which might not be supported by the hardware.
+    pub fn read_array(self) -> [T; N]
+    where
+        T: Copy,
+        A: Readable,
+    {
+        let mut array = MaybeUninit::<[T; N]>::uninit();
+        // SAFETY: the destination is valid for `N` elements
+        unsafe {
+            intrinsics::volatile_copy_nonoverlapping_memory(
+                array.as_mut_ptr().cast::<T>(),
+                self.pointer.as_ptr().cast::<T>(),
+                N,
+            );
+            array.assume_init()
+        }
+    }
+
+    /// Writes the given array using a volatile memcpy.
+    ///
+    /// In contrast to [`write`][VolatilePtr::write], this method doesn't perform a single
+    /// volatile write of the full array size, which might not be supported by the hardware.
+    pub fn write_array(self, array: [T; N])
+    where
+        T: Copy,
+        A: Writable,
+    {
+        self.copy_from(&array);
+    }
+
+    /// Copies all elements from `self` into `dst`, using a volatile memcpy.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let src = [1, 2];
+    /// let volatile = unsafe { VolatilePtr::new_read_only(NonNull::from(&src)) };
+    /// let mut dst = [0, 0];
+    /// volatile.copy_into(&mut dst);
+    /// assert_eq!(dst, [1, 2]);
+    /// ```
+    pub fn copy_into(self, dst: &mut [T; N])
+    where
+        T: Copy,
+        A: Readable,
+    {
+        // SAFETY: both arrays have `N` elements
+        unsafe {
+            intrinsics::volatile_copy_nonoverlapping_memory(
+                dst.as_mut_ptr(),
+                self.pointer.as_ptr().cast::<T>(),
+                N,
+            );
+        }
+    }
+
+    /// Copies all elements from `src` into `self`, using a volatile memcpy.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut dst = [0, 0];
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut dst)) };
+    /// volatile.copy_from(&[1, 2]);
+    /// assert_eq!(dst, [1, 2]);
+    /// ```
+    pub fn copy_from(self, src: &[T; N])
+    where
+        T: Copy,
+        A: Writable,
+    {
+        // SAFETY: both arrays have `N` elements
+        unsafe {
+            intrinsics::volatile_copy_nonoverlapping_memory(
+                self.pointer.as_ptr().cast::<T>(),
+                src.as_ptr(),
+                N,
+            );
+        }
+    }
+
+    /// Sets all elements of the array to the given `value`, using volatile writes.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut array = [0u16; 4];
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut array)) };
+    /// volatile.fill(0x0f20);
+    /// assert_eq!(array, [0x0f20; 4]);
+    /// ```
+    pub fn fill(self, value: T)
+    where
+        T: Copy,
+        A: Writable,
+    {
+        for element in self.iter() {
+            element.write(value);
+        }
+    }
+}
+
+/// Methods for two-dimensional slices, e.g. framebuffers or text screens
+///
+/// These methods are only available with the `unstable` feature enabled (requires a nightly
+/// Rust compiler).
+impl<'a, T, A, const N: usize> VolatilePtr<'a, [[T; N]], A> {
+    /// Converts a slice of `N`-element arrays into a flat slice.
+    ///
+    /// This is the inverse operation of [`as_chunks`][VolatilePtr::as_chunks].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the length of the resulting slice overflows a `usize`, which is only possible
+    /// for zero-sized types.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let array = [[1, 2], [3, 4]];
+    /// let volatile = unsafe { VolatilePtr::new_read_only(NonNull::from(&array[..])) };
+    /// let flat = volatile.flatten();
+    /// assert_eq!(flat.len(), 4);
+    /// assert_eq!(flat.index(2).read(), 3);
+    /// ```
+    pub fn flatten(self) -> VolatilePtr<'a, [T], A>
+    where
+        A: Access,
+    {
+        let len = self
+            .pointer
+            .len()
+            .checked_mul(N)
+            .expect("slice length overflow");
+        // SAFETY: `[[T; N]]` has the same layout as a `[T]` slice of `len * N` elements
+        unsafe {
+            self.map(|slice| {
+                NonNull::new_unchecked(ptr::slice_from_raw_parts_mut(
+                    slice.as_mut_ptr().cast(),
+                    len,
+                ))
+            })
+        }
+    }
+
+    /// Returns a pointer to the given row.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row(self, row: usize) -> VolatilePtr<'a, [T; N], A>
+    where
+        A: Access,
+    {
+        self.index(row)
+    }
+
+    /// Returns a pointer to the cell at the given `row` and `col`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `row` or `col` is out of bounds.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut screen = [[b' '; 80]; 25];
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut screen[..])) };
+    /// volatile.cell(24, 79).write(b'x');
+    /// assert_eq!(screen[24][79], b'x');
+    /// ```
+    pub fn cell(self, row: usize, col: usize) -> VolatilePtr<'a, T, A>
+    where
+        A: Access,
+    {
+        self.row(row).as_slice().index(col)
+    }
+
+    /// Moves all rows up by `n` rows and fills the freed rows at the end with `fill`.
+    ///
+    /// The first `n` rows are overwritten. If `n` is larger than the number of rows, all cells
+    /// are set to `fill`. The rows are moved using [`copy_within`][VolatilePtr::copy_within].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut screen = [*b"ab", *b"cd", *b"ef"];
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut screen[..])) };
+    /// volatile.scroll_rows(1, b' ');
+    /// assert_eq!(screen, [*b"cd", *b"ef", *b"  "]);
+    /// ```
+    pub fn scroll_rows(self, n: usize, fill: T)
+    where
+        T: Copy,
+        A: Readable + Writable,
+    {
+        let rows = self.pointer.len();
+        let n = n.min(rows);
+        let flat = self.flatten();
+        flat.copy_within(n * N.., 0);
+        for cell in flat.index((rows - n) * N..).iter() {
+            cell.write(fill);
+        }
+    }
+}
+
+/// Methods for two-dimensional arrays, e.g. framebuffers or text screens
+///
+/// These methods are only available with the `unstable` feature enabled (requires a nightly
+/// Rust compiler).
+impl<'a, T, A, const W: usize, const H: usize> VolatilePtr<'a, [[T; W]; H], A> {
+    /// Converts a two-dimensional array into a flat slice.
+    ///
+    /// See [`VolatilePtr::flatten`] for details.
+    pub fn flatten(self) -> VolatilePtr<'a, [T], A>
+    where
+        A: Access,
+    {
+        self.as_slice().flatten()
+    }
+
+    /// Returns a pointer to the given row.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row(self, row: usize) -> VolatilePtr<'a, [T; W], A>
+    where
+        A: Access,
+    {
+        self.as_slice().row(row)
+    }
+
+    /// Returns a pointer to the cell at the given `row` and `col`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `row` or `col` is out of bounds.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut screen = [[0u16; 80]; 25];
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(&mut screen)) };
+    /// volatile.cell(1, 2).write(0x0f41);
+    /// assert_eq!(volatile.row(1).as_slice().index(2).read(), 0x0f41);
+    /// ```
+    pub fn cell(self, row: usize, col: usize) -> VolatilePtr<'a, T, A>
+    where
+        A: Access,
+    {
+        self.as_slice().cell(row, col)
+    }
+
+    /// Moves all rows up by `n` rows and fills the freed rows at the end with `fill`.
+    ///
+    /// See [`VolatilePtr::scroll_rows`] for details.
+    pub fn scroll_rows(self, n: usize, fill: T)
+    where
+        T: Copy,
+        A: Readable + Writable,
+    {
+        self.as_slice().scroll_rows(n, fill)
+    }
+}
+
 fn bounds_check(len: usize, index: impl SliceIndex<[()]>) {
     const MAX_ARRAY: [(); usize::MAX] = [(); usize::MAX];
 
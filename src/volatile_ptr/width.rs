@@ -0,0 +1,182 @@
+use core::{
+    mem,
+    ptr::{self, NonNull},
+};
+
+use super::{read_no_tear, write_no_tear};
+use crate::{
+    access::{Readable, Writable},
+    width::{AccessWidth, NoTear},
+    VolatilePtr,
+};
+
+/// Methods for volatile byte slices that access the memory with a fixed width.
+///
+/// The individual accesses are performed like [`read_single`][VolatilePtr::read_single] and
+/// [`write_single`][VolatilePtr::write_single], so the access width `W` must implement
+/// [`NoTear`] in addition to [`AccessWidth`].
+impl<A> VolatilePtr<'_, [u8], A> {
+    /// Copies all bytes from `self` into `dst`, using volatile reads of exactly
+    /// `size_of::<W>()` bytes each.
+    ///
+    /// In contrast to a plain volatile copy, this method guarantees the
+    /// width of the individual accesses to the volatile memory. This is required for devices
+    /// that only support accesses of a specific width, e.g. SRAM that faults on byte accesses.
+    /// The destination slice `dst` is ordinary memory, so it does not need to be aligned.
+    ///
+    /// The length of `dst` must be the same as `self`.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if the two slices have different lengths, if the start of
+    /// `self` is not aligned to `align_of::<W>()`, or if the length of `self` is not a multiple
+    /// of `size_of::<W>()`. Unaligned head or tail bytes are never accessed with a narrower
+    /// width; split the slice and copy them separately if needed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let src = [0x1234_5678u32, 0x9abc_def0];
+    /// let bytes = unsafe { core::slice::from_raw_parts(src.as_ptr().cast::<u8>(), 8) };
+    /// let volatile = unsafe { VolatilePtr::new_read_only(NonNull::from(bytes)) };
+    /// let mut dst = [0; 8];
+    ///
+    /// // performs exactly two 32-bit reads
+    /// volatile.copy_into_slice_as::<u32>(&mut dst);
+    /// assert_eq!(dst, bytes);
+    /// ```
+    pub fn copy_into_slice_as<W>(self, dst: &mut [u8])
+    where
+        W: AccessWidth + NoTear,
+        A: Readable,
+    {
+        let len = self.pointer.len();
+        assert_eq!(
+            len,
+            dst.len(),
+            "destination and source slices have different lengths"
+        );
+        let count = width_count::<W>(self.pointer);
+        let src = self.pointer.as_ptr() as *const W;
+        let dst = dst.as_mut_ptr() as *mut W;
+        for i in 0..count {
+            // SAFETY: `width_count` checked that `src` is aligned and that `count` accesses of
+            // width `W` stay within the slice. The destination has the same length.
+            unsafe {
+                let value = read_no_tear(src.add(i));
+                ptr::write_unaligned(dst.add(i), value);
+            }
+        }
+    }
+
+    /// Copies all bytes from `src` into `self`, using volatile writes of exactly
+    /// `size_of::<W>()` bytes each.
+    ///
+    /// In contrast to a plain volatile copy, this method guarantees the
+    /// width of the individual accesses to the volatile memory. The source slice `src` is
+    /// ordinary memory, so it does not need to be aligned.
+    ///
+    /// The length of `src` must be the same as `self`.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if the two slices have different lengths, if the start of
+    /// `self` is not aligned to `align_of::<W>()`, or if the length of `self` is not a multiple
+    /// of `size_of::<W>()`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut dst = [0u32; 2];
+    /// let bytes = unsafe { core::slice::from_raw_parts_mut(dst.as_mut_ptr().cast::<u8>(), 8) };
+    /// let volatile = unsafe { VolatilePtr::new(NonNull::from(bytes)) };
+    ///
+    /// // performs exactly two 32-bit writes
+    /// volatile.copy_from_slice_as::<u32>(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(dst[0].to_ne_bytes(), [1, 2, 3, 4]);
+    /// assert_eq!(dst[1].to_ne_bytes(), [5, 6, 7, 8]);
+    /// ```
+    pub fn copy_from_slice_as<W>(self, src: &[u8])
+    where
+        W: AccessWidth + NoTear,
+        A: Writable,
+    {
+        let len = self.pointer.len();
+        assert_eq!(
+            len,
+            src.len(),
+            "destination and source slices have different lengths"
+        );
+        let count = width_count::<W>(self.pointer);
+        let dst = self.pointer.as_ptr() as *mut W;
+        let src = src.as_ptr() as *const W;
+        for i in 0..count {
+            // SAFETY: `width_count` checked that `dst` is aligned and that `count` accesses of
+            // width `W` stay within the slice. The source has the same length.
+            unsafe {
+                let value = ptr::read_unaligned(src.add(i));
+                write_no_tear(dst.add(i), value);
+            }
+        }
+    }
+
+    /// Sets all bytes of the slice to the given `value`, using volatile writes of exactly
+    /// `size_of::<W>()` bytes each.
+    ///
+    /// In contrast to a plain volatile fill, this method guarantees the width of the individual
+    /// accesses to the volatile memory.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if the start of `self` is not aligned to `align_of::<W>()`, or
+    /// if the length of `self` is not a multiple of `size_of::<W>()`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use volatile::VolatilePtr;
+    /// use core::ptr::NonNull;
+    ///
+    /// let mut words = [0u32; 2];
+    /// let bytes = unsafe { core::slice::from_raw_parts_mut(words.as_mut_ptr().cast::<u8>(), 8) };
+    /// let buf = unsafe { VolatilePtr::new(NonNull::from(bytes)) };
+    /// buf.fill_as::<u32>(0xff);
+    /// assert_eq!(words, [u32::MAX; 2]);
+    /// ```
+    pub fn fill_as<W>(self, value: u8)
+    where
+        W: AccessWidth + NoTear,
+        A: Writable,
+    {
+        let count = width_count::<W>(self.pointer);
+        let dst = self.pointer.as_ptr() as *mut W;
+        let value = W::splat(value);
+        for i in 0..count {
+            // SAFETY: `width_count` checked that `dst` is aligned and that `count` accesses of
+            // width `W` stay within the slice.
+            unsafe { write_no_tear(dst.add(i), value) };
+        }
+    }
+}
+
+/// Returns the number of `W`-sized accesses that are needed to cover the given byte slice.
+///
+/// Panics if the slice is not aligned to `W` or if its length is not a multiple of the size of `W`.
+fn width_count<W>(slice: NonNull<[u8]>) -> usize {
+    let size = mem::size_of::<W>();
+    assert!(
+        (slice.as_ptr().cast::<u8>() as usize).is_multiple_of(mem::align_of::<W>()),
+        "volatile slice is not aligned to the access width"
+    );
+    assert!(
+        slice.len().is_multiple_of(size),
+        "volatile slice length is not a multiple of the access width"
+    );
+    slice.len() / size
+}
@@ -1,6 +1,6 @@
 use crate::{
-    access::{Access, Copyable, ReadOnly, ReadWrite, WriteOnly},
-    volatile_ptr::VolatilePtr,
+    access::{Access, Copyable, ReadOnly, ReadWrite, Readable, RestrictTo, WriteOnly},
+    volatile_ptr::{DebugValue, VolatilePtr},
 };
 use core::{cmp::Ordering, fmt, hash, marker::PhantomData, ptr::NonNull};
 
@@ -55,7 +55,7 @@ where
     ///   chosen and does not necessarily reflect the actual lifetime of the data. In particular,
     ///   while this `VolatileRef` exists, the memory the pointer points to must not get accessed
     ///   (_read or written_) through any other pointer.
-    pub unsafe fn new(pointer: NonNull<T>) -> Self {
+    pub const unsafe fn new(pointer: NonNull<T>) -> Self {
         unsafe { VolatileRef::new_restricted(ReadWrite, pointer) }
     }
 
@@ -93,6 +93,45 @@ where
         unsafe { Self::new_generic(pointer) }
     }
 
+    /// Creates a `VolatileRef` for the `T` value at the given memory address.
+    ///
+    /// This is a `const` function, so it can be used to initialize `static` or `const` items
+    /// for memory-mapped devices at fixed addresses.
+    ///
+    /// ## Safety
+    ///
+    /// The address must be properly aligned for `T` and the requirements for [`Self::new`] must
+    /// be fulfilled for the resulting pointer.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `addr` is zero.
+    pub const unsafe fn from_addr(addr: usize) -> VolatileRef<'a, T, ReadWrite>
+    where
+        T: Sized,
+    {
+        unsafe { Self::from_addr_restricted(ReadWrite, addr) }
+    }
+
+    /// Creates a `VolatileRef` with restricted access for the `T` value at the given memory
+    /// address.
+    ///
+    /// ## Safety
+    ///
+    /// The requirements for [`Self::from_addr`] apply to this function too.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `addr` is zero.
+    pub const unsafe fn from_addr_restricted<A>(access: A, addr: usize) -> VolatileRef<'a, T, A>
+    where
+        T: Sized,
+        A: Access,
+    {
+        assert!(addr != 0, "address must not be zero");
+        unsafe { Self::new_restricted(access, NonNull::new_unchecked(addr as *mut T)) }
+    }
+
     /// Creates a `VolatileRef` from the given shared reference.
     ///
     /// **Note:** This function is only intended for testing, not for accessing real volatile
@@ -121,6 +160,21 @@ where
         unsafe { VolatileRef::new(reference.into()) }
     }
 
+    /// Turns the given `VolatilePtr` into a `VolatileRef` with the same lifetime and access.
+    ///
+    /// ## Safety
+    ///
+    /// The requirements for [`Self::new_restricted`] apply to this function too. In
+    /// particular, no other `VolatilePtr` or `VolatileRef` must be used to access the
+    /// referenced memory while the returned `VolatileRef` exists (unless both only allow
+    /// reads).
+    pub unsafe fn from_ptr<A>(pointer: VolatilePtr<'a, T, A>) -> VolatileRef<'a, T, A>
+    where
+        A: Access,
+    {
+        unsafe { VolatileRef::new_restricted(A::default(), pointer.as_raw_ptr()) }
+    }
+
     const unsafe fn new_generic<A>(pointer: NonNull<T>) -> VolatileRef<'a, T, A> {
         VolatileRef {
             pointer,
@@ -167,6 +221,89 @@ where
     {
         unsafe { VolatilePtr::new_restricted(Default::default(), self.pointer) }
     }
+
+    /// Borrows this `VolatileRef` as a shared `VolatileRef` with restricted access.
+    ///
+    /// Like a shared Rust reference, the returned `VolatileRef` only allows reads (if the
+    /// original access type allows reads).
+    pub fn borrow(&self) -> VolatileRef<'_, T, A::RestrictShared>
+    where
+        A: Access,
+    {
+        unsafe { VolatileRef::new_restricted(Default::default(), self.pointer) }
+    }
+
+    /// Reborrows this `VolatileRef` with full access for a shorter lifetime.
+    ///
+    /// This is useful for calling methods that consume a `VolatileRef`, e.g.
+    /// [`map`][Self::map], without giving up the original `VolatileRef`.
+    pub fn borrow_mut(&mut self) -> VolatileRef<'_, T, A>
+    where
+        A: Access,
+    {
+        unsafe { VolatileRef::new_restricted(Default::default(), self.pointer) }
+    }
+
+    /// Extracts the wrapped raw pointer.
+    pub fn as_raw_ptr(&self) -> NonNull<T> {
+        self.pointer
+    }
+
+    /// Constructs a new `VolatileRef` by mapping the wrapped pointer.
+    ///
+    /// This method is useful for accessing only a part of a volatile value, e.g. a subslice or
+    /// a struct field. For struct field access, there is also the safe
+    /// [`map_field`][crate::map_field] macro that wraps this function. To keep the original
+    /// `VolatileRef` usable, call this method on a [`borrow`][Self::borrow] or
+    /// [`borrow_mut`][Self::borrow_mut] reborrow.
+    ///
+    /// ## Safety
+    ///
+    /// The pointer returned by `f` must point into the value referenced by `self` and satisfy
+    /// the requirements of [`Self::new`].
+    pub unsafe fn map<F, U>(self, f: F) -> VolatileRef<'a, U, A>
+    where
+        F: FnOnce(NonNull<T>) -> NonNull<U>,
+        A: Access,
+        U: ?Sized,
+    {
+        unsafe { VolatileRef::new_restricted(A::default(), f(self.pointer)) }
+    }
+}
+
+/// Methods for printing the referenced value.
+impl<T, A> VolatileRef<'_, T, A>
+where
+    T: ?Sized,
+    A: Access,
+{
+    /// Returns an adapter that reads the referenced value when formatted through
+    /// [`fmt::Debug`].
+    ///
+    /// See [`VolatilePtr::debug_value`] for details.
+    pub fn debug_value(&self) -> DebugValue<'_, T, A::RestrictShared>
+    where
+        A::RestrictShared: Readable,
+    {
+        self.as_ptr().debug_value()
+    }
+}
+
+/// Methods for printing the elements of arrays.
+impl<T, A, const N: usize> VolatileRef<'_, [T; N], A>
+where
+    A: Access,
+{
+    /// Returns an adapter that reads the array elements one by one when formatted through
+    /// [`fmt::Debug`].
+    ///
+    /// See [`VolatilePtr::debug_elements`] for details.
+    pub fn debug_elements(&self) -> DebugValue<'_, [T], A::RestrictShared>
+    where
+        A::RestrictShared: Readable,
+    {
+        self.as_ptr().debug_elements()
+    }
 }
 
 /// Methods for restricting access.
@@ -216,6 +353,54 @@ where
     }
 }
 
+/// Methods for converting between access types.
+impl<'a, T, A> VolatileRef<'a, T, A>
+where
+    T: ?Sized,
+    A: Access,
+{
+    /// Restricts access permissions to the given access type `B`.
+    ///
+    /// This is the generic counterpart of [`read_only`][VolatileRef::read_only] and
+    /// [`write_only`][VolatileRef::write_only]. See [`RestrictTo`] for the possible
+    /// conversions.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use volatile::{access::ReadOnly, VolatileRef};
+    ///
+    /// let mut value: i16 = -4;
+    /// let volatile = VolatileRef::from_mut_ref(&mut value);
+    ///
+    /// let read_only = volatile.restrict::<ReadOnly>();
+    /// assert_eq!(read_only.as_ptr().read(), -4);
+    /// // read_only.restrict::<volatile::access::WriteOnly>(); // compile-time error
+    /// ```
+    pub fn restrict<B>(self) -> VolatileRef<'a, T, B>
+    where
+        A: RestrictTo<B>,
+        B: Access,
+    {
+        unsafe { VolatileRef::new_restricted(B::default(), self.pointer) }
+    }
+
+    /// Changes the access permissions to the given access type `B`, which might allow more
+    /// operations than the current one.
+    ///
+    /// ## Safety
+    ///
+    /// The referenced memory must support all operations allowed by `B`. Since `VolatileRef`
+    /// values with read-only access can be copied, no copies of this `VolatileRef` must be
+    /// used while the returned value allows writes.
+    pub unsafe fn upgrade_access<B>(self) -> VolatileRef<'a, T, B>
+    where
+        B: Access,
+    {
+        unsafe { VolatileRef::new_restricted(B::default(), self.pointer) }
+    }
+}
+
 impl<'a, T, A> Clone for VolatileRef<'a, T, A>
 where
     T: ?Sized,
@@ -254,6 +439,16 @@ where
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<T, A> defmt::Format for VolatileRef<'_, T, A>
+where
+    T: ?Sized,
+{
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{}", self.pointer.as_ptr().cast::<()>())
+    }
+}
+
 impl<T, A> PartialEq for VolatileRef<'_, T, A>
 where
     T: ?Sized,
@@ -270,7 +465,7 @@ where
     T: ?Sized,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(Ord::cmp(&self.pointer.as_ptr(), &other.pointer.as_ptr()))
+        Some(Ord::cmp(self, other))
     }
 }
 
@@ -278,6 +473,9 @@ impl<T, A> Ord for VolatileRef<'_, T, A>
 where
     T: ?Sized,
 {
+    // Like `PartialEq` and `Hash`, the ordering includes the pointer metadata (e.g. the
+    // length of slices), so that it stays consistent with `Eq`.
+    #[allow(ambiguous_wide_pointer_comparisons)]
     fn cmp(&self, other: &Self) -> Ordering {
         Ord::cmp(&self.pointer.as_ptr(), &other.pointer.as_ptr())
     }
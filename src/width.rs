@@ -0,0 +1,81 @@
+//! Traits for controlling the width of volatile accesses.
+
+use crate::access::_Private;
+
+/// Primitive integer types that can be used as the access width of bulk operations.
+///
+/// Methods such as [`VolatilePtr::copy_from_slice_as`][crate::VolatilePtr::copy_from_slice_as]
+/// split the transfer into volatile accesses of exactly `size_of::<W>()` bytes each, where `W`
+/// is a type implementing this trait.
+///
+/// This trait is implemented for `u8`, `u16`, `u32`, and `u64`. The bulk methods additionally
+/// require the width to implement [`NoTear`], so `u64` is only usable on 64-bit targets.
+pub trait AccessWidth: Copy {
+    /// Returns a value that has every byte set to `byte`.
+    fn splat(byte: u8) -> Self;
+
+    /// Ensures that this trait cannot be implemented outside of this crate.
+    #[doc(hidden)]
+    fn _private() -> _Private {
+        _Private
+    }
+}
+
+impl AccessWidth for u8 {
+    fn splat(byte: u8) -> Self {
+        byte
+    }
+}
+
+impl AccessWidth for u16 {
+    fn splat(byte: u8) -> Self {
+        Self::from_ne_bytes([byte; 2])
+    }
+}
+
+impl AccessWidth for u32 {
+    fn splat(byte: u8) -> Self {
+        Self::from_ne_bytes([byte; 4])
+    }
+}
+
+impl AccessWidth for u64 {
+    fn splat(byte: u8) -> Self {
+        Self::from_ne_bytes([byte; 8])
+    }
+}
+
+/// Types that the target can read and write with a single instruction.
+///
+/// With the `mmio-asm` feature enabled,
+/// [`VolatilePtr::read_single`][crate::VolatilePtr::read_single] and
+/// [`VolatilePtr::write_single`][crate::VolatilePtr::write_single] use inline assembly that emits
+/// exactly one plain load or store instruction for these types on `x86_64` and `aarch64`. This
+/// is required for MMIO regions that are emulated by a hypervisor, which often can't handle
+/// load/store pair instructions or addressing modes with writeback. Use this trait as a bound in
+/// driver code to limit register types to types that can't be torn.
+///
+/// This trait is implemented for all primitive integer types that fit into a general purpose
+/// register of the target.
+///
+/// ## Safety
+///
+/// Implementors must be naturally aligned primitive integer types whose size is 1, 2, 4 or 8
+/// bytes and at most the pointer width of the target. In particular, they must not contain any
+/// padding bytes.
+pub unsafe trait NoTear: Copy {}
+
+unsafe impl NoTear for u8 {}
+unsafe impl NoTear for i8 {}
+unsafe impl NoTear for u16 {}
+unsafe impl NoTear for i16 {}
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+unsafe impl NoTear for u32 {}
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+unsafe impl NoTear for i32 {}
+#[cfg(target_pointer_width = "64")]
+unsafe impl NoTear for u64 {}
+#[cfg(target_pointer_width = "64")]
+unsafe impl NoTear for i64 {}
+unsafe impl NoTear for usize {}
+unsafe impl NoTear for isize {}
@@ -0,0 +1,313 @@
+//! A [`fmt::Write`] sink for volatile text buffers.
+//!
+//! The [`VolatileWriter`] type writes formatted text to a volatile slice, e.g. a VGA-style
+//! text buffer or a memory-mapped log area. This makes it possible to use the `write!` macro
+//! directly on device memory.
+
+use core::fmt;
+
+use crate::VolatilePtr;
+
+/// Specifies what happens when the end of the buffer is reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Continue writing at the start of the buffer, overwriting the oldest output.
+    Wrap,
+    /// Discard all further output.
+    Truncate,
+    /// Move the buffer contents up by one line and continue writing on the freed last line.
+    ///
+    /// Without a line length, the contents are moved by a single cell.
+    Scroll,
+}
+
+/// Converts characters to buffer cells.
+///
+/// This trait is implemented for all `FnMut(char) -> T` closures, which map every character to
+/// exactly one cell. The [`Utf8`] encoder writes the UTF-8 representation of the characters to
+/// byte buffers.
+///
+/// A character is encoded to at most [`MAX_CELLS`] cells. Additional cells are discarded.
+pub trait Encode<T> {
+    /// Encodes the given character and passes the resulting cells to `put`.
+    fn encode(&mut self, c: char, put: &mut dyn FnMut(T));
+}
+
+impl<T, F> Encode<T> for F
+where
+    F: FnMut(char) -> T,
+{
+    fn encode(&mut self, c: char, put: &mut dyn FnMut(T)) {
+        put(self(c))
+    }
+}
+
+/// The maximum number of cells that a single character is encoded to.
+pub const MAX_CELLS: usize = 4;
+
+/// Encodes characters as UTF-8 bytes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Utf8;
+
+impl Encode<u8> for Utf8 {
+    fn encode(&mut self, c: char, put: &mut dyn FnMut(u8)) {
+        for &byte in c.encode_utf8(&mut [0; 4]).as_bytes() {
+            put(byte);
+        }
+    }
+}
+
+/// A cursor-based writer for volatile text buffers that implements [`fmt::Write`].
+///
+/// ## Examples
+///
+/// Writing to a byte buffer:
+///
+/// ```
+/// use volatile::{writer::{Overflow, VolatileWriter}, VolatilePtr};
+/// use core::{fmt::Write, ptr::NonNull};
+///
+/// let mut log = [0u8; 16];
+/// let buffer = unsafe { VolatilePtr::new(NonNull::from(&mut log[..])) };
+/// let mut writer = VolatileWriter::new(buffer, Overflow::Truncate);
+/// write!(writer, "value: {}", 42).unwrap();
+///
+/// assert_eq!(&log[..9], b"value: 42");
+/// ```
+///
+/// Writing to a VGA-style text buffer with 2 lines of 4 characters, using a custom encoder that
+/// combines each character with a color attribute:
+///
+/// ```
+/// use volatile::{writer::{Overflow, VolatileWriter}, VolatilePtr};
+/// use core::{fmt::Write, ptr::NonNull};
+///
+/// let mut screen = [(b' ', 0u8); 8];
+/// let buffer = unsafe { VolatilePtr::new(NonNull::from(&mut screen[..])) };
+/// let encoder = |c: char| (if c.is_ascii() { c as u8 } else { b'?' }, 0x0f);
+/// let mut writer = VolatileWriter::with_encoder(buffer, encoder, Overflow::Scroll)
+///     .with_line_len(4);
+/// write!(writer, "ab\ncd\nef").unwrap();
+///
+/// let text: Vec<u8> = screen.iter().map(|(c, _)| *c).collect();
+/// assert_eq!(&text, b"cd  ef  ");
+/// ```
+pub struct VolatileWriter<'a, T, E> {
+    cursor: Cursor<'a, T>,
+    encoder: E,
+}
+
+impl<'a> VolatileWriter<'a, u8, Utf8> {
+    /// Creates a writer that writes UTF-8 encoded text to the given byte buffer.
+    pub fn new(buffer: VolatilePtr<'a, [u8]>, overflow: Overflow) -> Self {
+        VolatileWriter::with_encoder(buffer, Utf8, overflow)
+    }
+}
+
+impl<'a, T, E> VolatileWriter<'a, T, E>
+where
+    T: Copy,
+    E: Encode<T>,
+{
+    /// Creates a writer that uses the given `encoder` to convert characters to buffer cells.
+    ///
+    /// When scrolling, the freed cells are filled with the first cell of the encoded `' '`
+    /// character.
+    pub fn with_encoder(buffer: VolatilePtr<'a, [T]>, mut encoder: E, overflow: Overflow) -> Self {
+        let mut blank = None;
+        encoder.encode(' ', &mut |cell| {
+            blank.get_or_insert(cell);
+        });
+        VolatileWriter {
+            cursor: Cursor {
+                buffer,
+                position: 0,
+                line_len: None,
+                overflow,
+                blank,
+            },
+            encoder,
+        }
+    }
+
+    /// Treats the buffer as lines of `line_len` cells.
+    ///
+    /// With a line length, a `'\n'` character moves the cursor to the start of the next
+    /// line instead of being encoded, and [`Overflow::Scroll`] moves the contents by a full
+    /// line.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `line_len` is zero.
+    pub fn with_line_len(mut self, line_len: usize) -> Self {
+        assert_ne!(line_len, 0, "line length must not be zero");
+        self.cursor.line_len = Some(line_len);
+        self
+    }
+
+    /// Returns the index of the cell that is written next.
+    pub fn position(&self) -> usize {
+        self.cursor.position
+    }
+
+    /// Moves the cursor to the given cell index.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `position` is larger than the buffer length.
+    pub fn set_position(&mut self, position: usize) {
+        assert!(position <= self.cursor.len(), "position is out of bounds");
+        self.cursor.position = position;
+    }
+}
+
+impl<T, E> fmt::Write for VolatileWriter<'_, T, E>
+where
+    T: Copy,
+    E: Encode<T>,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match self.cursor.line_len {
+                Some(line_len) if c == '\n' => self.cursor.new_line(line_len),
+                _ => {
+                    let mut cells = [None; MAX_CELLS];
+                    let mut count = 0;
+                    self.encoder.encode(c, &mut |cell| {
+                        if let Some(slot) = cells.get_mut(count) {
+                            *slot = Some(cell);
+                            count += 1;
+                        }
+                    });
+                    self.cursor
+                        .put(cells[..count].iter().flatten().copied(), count);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+struct Cursor<'a, T> {
+    buffer: VolatilePtr<'a, [T]>,
+    position: usize,
+    line_len: Option<usize>,
+    overflow: Overflow,
+    blank: Option<T>,
+}
+
+impl<T> Cursor<'_, T>
+where
+    T: Copy,
+{
+    fn len(&self) -> usize {
+        self.buffer.as_raw_ptr().len()
+    }
+
+    /// Writes the `count` cells of a single character.
+    ///
+    /// The cells are only written if all of them fit into the buffer, so that multi-cell
+    /// characters are never split.
+    fn put(&mut self, cells: impl Iterator<Item = T>, count: usize) {
+        let len = self.len();
+        if count > len {
+            return;
+        }
+        if self.position + count > len {
+            match self.overflow {
+                Overflow::Wrap => self.position = 0,
+                Overflow::Truncate => {
+                    // discard all further output, including shorter characters
+                    self.position = len;
+                    return;
+                }
+                Overflow::Scroll => {
+                    while self.position + count > len {
+                        self.scroll();
+                    }
+                }
+            }
+        }
+        for cell in cells {
+            // SAFETY: we checked that all cells fit into the buffer
+            unsafe { self.buffer.element_unchecked(self.position) }.write(cell);
+            self.position += 1;
+        }
+    }
+
+    fn new_line(&mut self, line_len: usize) {
+        let next = (self.position / line_len + 1) * line_len;
+        self.position = next.min(self.len());
+    }
+
+    fn scroll(&mut self) {
+        let len = self.len();
+        let shift = self.line_len.unwrap_or(1).min(len);
+        for i in shift..len {
+            // SAFETY: both `i` and `i - shift` are smaller than `len`
+            unsafe {
+                let cell = self.buffer.element_unchecked(i).read();
+                self.buffer.element_unchecked(i - shift).write(cell);
+            }
+        }
+        if let Some(blank) = self.blank {
+            for i in len - shift..len {
+                // SAFETY: `i` is smaller than `len`
+                unsafe { self.buffer.element_unchecked(i) }.write(blank);
+            }
+        }
+        self.position = self.position.saturating_sub(shift);
+    }
+}
+
+impl<T, E> fmt::Debug for VolatileWriter<'_, T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VolatileWriter")
+            .field("buffer", &self.cursor.buffer)
+            .field("position", &self.cursor.position)
+            .field("line_len", &self.cursor.line_len)
+            .field("overflow", &self.cursor.overflow)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Overflow, VolatileWriter};
+    use crate::VolatilePtr;
+    use core::{fmt::Write, ptr::NonNull};
+
+    fn write_to(buffer: &mut [u8], overflow: Overflow, s: &str) {
+        let buffer = unsafe { VolatilePtr::new(NonNull::from(buffer)) };
+        let mut writer = VolatileWriter::new(buffer, overflow);
+        writer.write_str(s).unwrap();
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut buffer = [0; 4];
+        write_to(&mut buffer, Overflow::Wrap, "abcdef");
+        assert_eq!(&buffer, b"efcd");
+        write_to(&mut buffer, Overflow::Truncate, "ghijkl");
+        assert_eq!(&buffer, b"ghij");
+        write_to(&mut buffer, Overflow::Scroll, "mnopq\n");
+        assert_eq!(&buffer, b"opq\n");
+        write_to(&mut buffer, Overflow::Truncate, "ä");
+        assert_eq!(&buffer, "äq\n".as_bytes());
+    }
+
+    #[test]
+    fn test_multi_byte_overflow() {
+        let mut buffer = [0; 4];
+        write_to(&mut buffer, Overflow::Truncate, "abcäd");
+        assert_eq!(&buffer, b"abc\0");
+        write_to(&mut buffer, Overflow::Wrap, "xyzä");
+        assert_eq!(&buffer, "äz\0".as_bytes());
+        write_to(&mut buffer, Overflow::Scroll, "abcä");
+        assert_eq!(&buffer, "bcä".as_bytes());
+        write_to(&mut buffer, Overflow::Scroll, "😀");
+        assert_eq!(&buffer, "😀".as_bytes());
+        write_to(&mut buffer[..3], Overflow::Wrap, "😀");
+        assert_eq!(&buffer, "😀".as_bytes());
+    }
+}